@@ -28,7 +28,10 @@
 /// intermediate expansion steps are possible.
 ///
 /// `eager!` does not work with any macro; only macros declared using [`eager_macro_rules!`] may be
-/// used. Such macros are said to be `eager!`-enabled.
+/// used. Such macros are said to be `eager!`-enabled. They may be called either by name or through
+/// a path, e.g. `some_crate::some_macro!()`, just like outside `eager!`.
+/// Whether a macro is `eager!`-enabled can be checked at compile time using
+/// [`assert_eager_enabled!`](macro.assert_eager_enabled.html).
 ///
 /// To enable the use of non-`eager!`-enabled macros inside an `eager!` call,
 /// a `lazy!` block can be inserted. Everything inside the `lazy!` block will be lazily expanded,
@@ -36,17 +39,189 @@
 /// to the usual rules for macro expansion, an `eager!` block can be inserted inside the `lazy!`
 /// block, to re-enable eager expansion for some subset of it.
 ///
+/// Calling a macro that is not `eager!`-enabled inside `eager!`, without `lazy!`, gives the
+/// compiler's own error for a call that matches none of the macro's rules, pointing at
+/// the `eager!` call and at the declaration of the called macro:
+/// ```text
+/// error: no rules expected `@`
+///   --> src/lib.rs:9:5
+///    |
+/// 1  | macro_rules! plain{
+///    | ------------------ when calling this macro
+/// ...
+/// 9  |     eager!{plain!()}
+///    |     ^^^^^^^^^^^^^^^^ no rules expected this token in macro call
+/// ```
+/// `assert_eager_enabled!(plain)` finds this before the macro is used, failing with an error
+/// that no rules expected the message `"assert_eager_enabled!: this macro is not eager!-enabled, ..."`.
+/// A macro with a rule that accepts any input, e.g. `($($input:tt)*)`, gives no such error
+/// inside `eager!`, but its expansion replaces the rest of the input.
+/// For such a macro, `assert_eager_enabled!` instead fails with a type error, saying that
+/// `__macro_is_not_eager_enabled_so_wrap_it_in_lazy` was expected.
+///
+/// Macros from the standard library, e.g. `println!`, `vec!` or `format!`, need no `lazy!` block.
+/// `eager!` leaves them for the compiler to expand, but still eagerly expands the macro calls
+/// in their input, e.g. `vec![add!(1, 2)]`. The exceptions are macros that need their input
+/// as is, e.g. `stringify!`, `include_str!`, `env!` or `asm!`, whose input is left unchanged.
+/// Calls through a path starting with `std`, `core` or `alloc`, with or without the leading `::`,
+/// are also treated this way, even if a local item has the same name.
+/// Note that this means an `eager!`-enabled macro with the same name as a standard library
+/// macro cannot be called eagerly by name, only through a path, e.g. `self::write!(...)`.
+///
+/// To stop macros that never finish expanding, a budget of fuel can be given as the first input
+/// to `eager!`, e.g. `eager!{@fuel[2 0 0 0] ...}`. Since a macro can't take a number apart,
+/// its digits must be separated by spaces. Each step of decoding the input uses one fuel,
+/// and each eager expansion of a macro uses as much as the steps its call and return take.
+/// When no fuel is left, the expansion stops with an error naming the macro that was to be
+/// expanded, or whose input was being decoded, and the input that remained:
+/// ```compile_fail
+/// #[macro_use]
+/// extern crate dmutil;
+///
+/// eager_macro_rules!{ $eager_1
+///     macro_rules! forever{
+///         ()=>{forever!()};
+///     }
+/// }
+///
+/// fn main(){
+///     eager!{@fuel[1 0 0] forever!()}; // error: eager!: ran out of fuel before expanding 'forever !'
+/// }
+/// ```
+/// Each step is about one level of recursion, so with a fuel a bit below the recursion limit,
+/// the fuel runs out first. E.g. with the default limit of 128, a fuel of up to about 110 stops
+/// with the error above instead of reaching the limit.
+/// A larger fuel needs a higher `#![recursion_limit]` to be of use.
+/// The fuel needed is easiest found by trying, since it depends on how the input is decoded:
+/// ```
+/// #[macro_use]
+/// extern crate dmutil;
+///
+/// eager_macro_rules!{ $eager_1
+///     macro_rules! one{
+///         ()=>{1};
+///     }
+/// }
+///
+/// fn main(){
+///     assert_eq!(2, eager!{@fuel[2 5] one!() + one!()});
+/// }
+/// ```
+///
+/// To only expand some of the macros, a depth can be given, e.g. `eager!{@depth[1] ...}`.
+/// With a depth of `n`, only macro calls that are the result of fewer than `n` expansions
+/// are eagerly expanded. E.g. with `@depth[1]`, only the macro calls in the input are expanded,
+/// while the calls their expansions contain are output as is, to be expanded lazily like normal.
+/// A depth of 0 is the same as wrapping the input in `lazy!`.
+/// Like the fuel, the depth must be a number with its digits separated by spaces, e.g. `@depth[1 2]`.
+/// Both can be given, e.g. `eager!{@fuel[1 0 0] @depth[2] ...}`.
+///
+/// To give the fully expanded result to a macro that is not `eager!`-enabled, instead of
+/// outputting it, the macro can be given with `@into`, e.g. `eager!{@into[some_crate::consume!] ...}`
+/// ends by calling `some_crate::consume!{...}` with the result:
+/// ```
+/// #[macro_use]
+/// extern crate dmutil;
+///
+/// eager_macro_rules!{ $eager_1
+///     macro_rules! two_and_three{
+///         ()=>{2,3}
+///     }
+/// }
+///
+/// macro_rules! count{
+///     () => {0};
+///     ($first:tt $($rest:tt)*) => {1 + count!($($rest)*)};
+/// }
+///
+/// fn main(){
+///     assert_eq!(3, eager!{@into[count!] two_and_three!()});
+/// }
+/// ```
+/// The `!` is required, so giving just a name is an error:
+/// ```compile_fail
+/// #[macro_use]
+/// extern crate dmutil;
+///
+/// fn main(){
+///     // error: eager!: '@into' must be given the path to a macro followed by '!',
+///     // e.g. '@into[some_crate::consume!]', but was given 'stringify'
+///     let s = eager!{@into[stringify] 1 2 3};
+/// }
+/// ```
+///
+/// To only expand some macros, they can be listed with `@only`, e.g.
+/// `eager!{@only[add, two_and_three] ...}`. Any other macro call is then treated as if it
+/// was inside a `lazy!` block, including its input, so other macros that are not
+/// `eager!`-enabled can be used freely. Standard library macros are still handled as above.
+/// Likewise, `@except[...]` treats the listed macros as lazy and expands every other macro eagerly.
+/// The macros given to `@only` must be `eager!`-enabled and in scope by the given name or path,
+/// since `eager!` asks each of them whether a call is to it. For `@except`, `eager!` instead asks
+/// the called macro whether it is one of the listed macros, so these need not be `eager!`-enabled
+/// or in scope. Only the names of the macros are compared, so calls through a path match
+/// the listed macro with the same name:
+/// ```
+/// #[macro_use]
+/// extern crate dmutil;
+///
+/// eager_macro_rules!{ $eager_1
+///     macro_rules! add{
+///         ($e1:expr, $e2:expr)=> {$e1 + $e2}
+///     }
+///
+///     macro_rules! two_and_three{
+///         ()=>{2,3}
+///     }
+/// }
+///
+/// fn main(){
+///     let x = eager!{@only[add, two_and_three]{
+///         let sum = add!(two_and_three!());
+///         format!("{}", sum)
+///     }};
+///     assert_eq!("5", x);
+/// }
+/// ```
+/// A macro can only tell whether it has a given name if it is `eager!`-enabled,
+/// so with `@except`, calls to other macros must still be in a `lazy!` block,
+/// even if they are listed. `eager!` can't compare the names itself, since a macro can only
+/// compare a name with one written in its rules, and `eager!` may be used where no macro can be
+/// defined for the listed names, e.g. as an expression. So the error is from the called macro:
+/// ```compile_fail
+/// #[macro_use]
+/// extern crate dmutil;
+///
+/// eager_macro_rules!{ $eager_1
+///     macro_rules! one{
+///         ()=>{1};
+///     }
+/// }
+///
+/// macro_rules! plain{
+///     ($e:expr)=>{$e};
+/// }
+///
+/// fn main(){
+///     assert_eq!(2, eager!{@except[plain] lazy!{plain!(one!())} + one!()});
+///     // error: no rules expected `@`, when calling 'plain!'
+///     let x = eager!{@except[plain] plain!(one!()) + one!()};
+/// }
+/// ```
+///
 /// [`eager_macro_rules!`]: macro.eager_macro_rules.html
 /// [`lazy!`]: macro.lazy.html
 /// # Cons
 ///
 /// * Because of the way `eager!` is implemented - being a hack of recursive macros - the compiler's
-/// default macro recursion limit is quickly exceeded. Therefore, `#![recursion_limit="256"]`
-/// must be used in most situations - potentially with a higher limit -
-/// such that expansion can happen.
+/// default macro recursion limit can be exceeded. Simple tokens are handled several at a time,
+/// and several items are expanded each on their own, but each block and macro call,
+/// as well as each item before the one being expanded, adds to the recursion depth,
+/// so larger inputs may need
+/// `#![recursion_limit="256"]` - potentially with a higher limit - such that expansion can happen.
 ///
 /// * Debugging an eagerly expanded macro is very difficult and requires intimate knowledge
-/// of the implementation of `eager!`. There is no way to mitigate this, except to try and
+/// of the implementation of `eager!`. [`eager_trace!`](macro.eager_trace.html) can show each
+/// macro call of the expansion, but otherwise the only way to mitigate this is to try and
 /// recreate the bug without using `eager!`. Likewise, the error messages the compiler will
 /// emit are exponentially more cryptic than they already would have been.
 ///
@@ -154,6 +329,19 @@
 /// but in the worst case it can be put around the whole item
 /// (struct, trait, implement, function, etc.).
 ///
+/// It can also be put around several items or statements at once. When the input starts with
+/// an item or a statement (e.g. `#[...]`, `pub`, `fn`, `struct`, `impl`, `mod` or `let`),
+/// each of them is expanded on its own, such that the recursion depth needed for expanding
+/// one does not add to that of the others. Finding where the items end is still done one
+/// item after another, taking a level for every eight tokens outside the blocks of an item
+/// and one more for each of its blocks. So the depth needed grows with the largest item plus
+/// about two levels for each item before it, and a module of many items may still need
+/// a higher recursion limit.
+/// An item ends at a `;`, or at a block that is followed by a name or keyword (except `else`
+/// and `as`) or `#`, but not by a call to a macro by name, which may continue the item.
+/// Options are given to each item, so e.g. `@fuel[5 0]` gives each item 50 fuel.
+/// The input is not split when its result is given to a macro with `@into`.
+///
 ///
 /// ### No intermediate expansion step can include invalid syntax
 ///
@@ -246,8 +434,8 @@
 ///
 /// ### Auxiliary variable
 ///
-/// The auxiliary variable that must always be provided to `eager_macro_rules!`
-/// must use the identifier `eager_1`. This makes it easier for everyone to
+/// `eager_macro_rules!` no longer needs an auxiliary variable, only a `$`. Where one is still
+/// given, it must use the identifier `eager_1`. This makes it easier for everyone to
 /// get used to its presence and ignore it. By having it be the same in every project,
 /// no one has to think about why a given project uses some specific identifier.
 ///
@@ -257,7 +445,9 @@
 /// it is nested or a macro expands into an `eager!` block.
 /// Likewise, `eager_macro_rules!` is not `eager!`-enabled, though this might be possible.
 ///
-/// * `lazy!` is treated by `eager!` as a keyword and not a macro.
+/// * `lazy!` is treated by `eager!` as a keyword and not a macro. This is also the case when it is
+/// called through the crate, i.e. `dmutil::lazy!`, `::dmutil::lazy!` or `$crate::lazy!`. Through
+/// any other path, e.g. if the crate is renamed, it is called like any other macro.
 ///
 /// * `eager_macro_rules!`'s auxiliary variable is affectionately called `Simon`.
 /// This nickname should probably not be used as the identifier in production code.
@@ -271,13 +461,97 @@
 ///
 #[macro_export]
 macro_rules! eager{
+	(
+		@fuel $($all:tt)*
+	)=>{
+		$crate::eager_internal!{
+			@options[[][][][][]]
+			@fuel $($all)*
+		}
+	};
+	(
+		@depth $($all:tt)*
+	)=>{
+		$crate::eager_internal!{
+			@options[[][][][][]]
+			@depth $($all)*
+		}
+	};
+	(
+		@only $($all:tt)*
+	)=>{
+		$crate::eager_internal!{
+			@options[[][][][][]]
+			@only $($all)*
+		}
+	};
+	(
+		@except $($all:tt)*
+	)=>{
+		$crate::eager_internal!{
+			@options[[][][][][]]
+			@except $($all)*
+		}
+	};
+	(
+		@into $($all:tt)*
+	)=>{
+		$crate::eager_internal!{
+			@options[[][][][][]]
+			@into $($all)*
+		}
+	};
+	(	// Without options, the input may still be several items
+		$($all:tt)*
+	)=>{
+		$crate::eager_internal!{
+			@start[[[][][][]]]
+			$($all)*
+		}
+	};
+}
+
+///
+/// Like [`eager!`], but instead of the result, produces a `&'static [&'static str]` tracing
+/// how the expansion went.
+///
+/// Each time a macro is eagerly expanded, the level that is used to decode the call
+/// is stringified and added to the trace. The last element is the final level, whose prefix is the
+/// result. The levels use the format `[ [] [] [] [] {} [] ]` described in the
+/// design notes of `eager!`, i.e. the mode, the modefix, the prefix, the postfix,
+/// the block given to the macro, and the path to the macro.
+///
+/// Like `eager!`, it accepts a fuel budget as its first input, e.g. `eager_trace!{@fuel[1 0 0] ...}`.
+///
+/// # Example
+/// ```
+/// #[macro_use]
+/// extern crate dmutil;
+///
+/// eager_macro_rules!{ $eager_1
+///     macro_rules! plus_1{
+///         ()=>{+ 1};
+///     }
+/// }
+///
+/// fn main(){
+///     let trace = eager_trace!{2 plus_1!()};
+///     assert_eq!(2, trace.len());
+///     assert!(trace[0].contains("plus_1"));
+///     for level in trace {
+///         println!("{}", level);
+///     }
+/// }
+/// ```
+///
+/// [`eager!`]: macro.eager.html
+#[macro_export]
+macro_rules! eager_trace{
 	(
 		$($all:tt)*
 	)=>{
-		eager_internal!{
-			@check_expansion[
-				[[][][][]]
-			]
+		$crate::eager_internal!{
+			@options[[][][][][@trace[]]]
 			$($all)*
 		}
 	};
@@ -285,8 +559,8 @@ macro_rules! eager{
 
 /*
 Decoded format:
-[ [] [] [] [] {} ]
-  1  2  3  4  5
+[ [] [] [] [] {} [] ]
+  1  2  3  4  5  6
 
 1. The mode, either `[]` for eager or `[@lazy]` for lazy. Specifies whether the
 current decode mode is eager or lazy. If there is more input, then that input must be
@@ -296,8 +570,8 @@ decoded in the mode. If there is no input, then the modefix must be decoded in t
 to be decoded in the opposite mode to what is in 1.
 
 3. The block prefix, i.e. input that came before the current block (if there is one).
-Contains input that has been decoded and expanded completely. It is in
-reverse order that what it should be in the final result.
+Contains input that has been decoded and expanded completely, in the same order as
+it should be in the final result.
 
 4. The block postfix, i.e. input that came after the current block. If there is no
 block, then this must be empty.
@@ -307,16 +581,85 @@ of the block in the input. While the contents of the block are being decoded, it
 When the content has been decoded, checked, and expanded where appropriate it is input into
 the block.
 
+6. The macro being called, optional, and only present if there is a block. If the block is the
+input to a macro call that must be eagerly expanded, the path to the macro, e.g.
+`[some_macro]` or `[some_crate::some_macro]`.
+
+Below all the levels, the bottom of the stack may have a configuration group `{}`, which is only
+present if the `eager!` call was given options other than the fuel. It can hold the following, in order:
+
+* The depth: `@depth[[. .] [. . .] ...]`, a stack of how many more expansions deep we are
+allowed to go, each in unary. When a macro returns, and the top is `[.]`, the expansion is output
+as is, like with `no_rescan`. Otherwise, one less `.` is pushed to the stack, and the expansion
+is followed by `@__dmutil_end_of_expansion` in the input. When the decoding reaches it, the
+top of the stack is popped.
+
+* The filter: `@filter[only [[path] ...]]` or `@filter[except [[name] ...]]`, the macros given
+to `@only` or `@except`, where only the last segment of each path is kept for `@except`.
+Before a macro is called eagerly with `@only`, each listed macro is called with
+`@__dmutil_is[@__dmutil_version[1] [$crate::eager_internal] name] [yes...] [no...]`, where `name`
+is the last segment of the called macro's path. The listed macro replies to the return path with
+`@from_is`, the version and the return path, followed by `yes` if `name` is its own name, otherwise
+by `no`. Like when a macro returns, the version is checked before continuing the decoding with the reply.
+With `@except`, it is the other way around: the called macro is asked the same for each listed name,
+such that the listed macros need not be `eager!`-enabled.
+The names can't be compared here instead: macro_rules has no way of matching one name given to it
+with another, and a macro made for the comparison, with the listed name in its rule, can't be
+defined where `eager!` is used as an expression.
+A call that may not be expanded has its path moved to the prefix, and its block decoded in lazy mode.
+
+* The macro to give the result to: `@into[path!]`. When finished, the result is given to it
+instead of being output.
+
+* The trace: `@trace[...]`, only present when using `eager_trace!`. Each time a macro is called
+eagerly, the stringified level is added to it. When finished, the trace is output instead of
+the result.
+
+Below the configuration group, the fuel may be given as `(. . .)`, where each `.` is one fuel
+(i.e. the fuel is in unary). It is kept apart from the configuration group, such that each step
+can take one `.` from it without knowing the rest of the state, by matching the levels, the
+configuration group and the fuel separately. The steps that call a macro take as much as
+the levels of recursion the call and the return from it need, and the ones that ask whether
+a macro is listed in `@only` or `@except` as much as the asking needs. A step that can't take the fuel it needs doesn't
+match, and is followed by one that emits an error instead, naming the macro it would have called.
+When no step matches because the fuel is `()`, the last rules emit an error with the input left.
+
+## Top-level items
+
+If the input to `eager!` starts with a token that may start an item or a statement,
+it is first split into items, before any decoding happens. Like the decoding of simple tokens,
+the splitter adds up to eight tokens to the item at once, stopping before the first `;` or block
+among them. An item ends after a `;`, or after a block followed by a token that starts an item,
+which is only looked at when the block is the next token. Since macro_rules can't match
+one of several tokens, any name or keyword is taken to start an item, except `else` and
+`as`, which continue an expression, and a name followed by `!`, which may be a macro call
+continuing the item. When an item ends, it is decoded in its own invocation,
+while the rest of the input is split in a sibling invocation. Therefore, the recursion
+depth of decoding an item grows with the steps needed to scan the input before it,
+but not with the decoding of the items before it. A step can't know where the next item
+ends before it is scanned, so scanning is still one step after another, and the depth
+needed grows by about two levels for each item ending with a block, and more for long items.
+The options are given to each item, except `@into` and tracing, whose result must be one.
+
+Since each item is its own invocation, the items must each be valid on their own in the
+position of the `eager!` call, which is the case for items and statements.
+
 ## Decoding workflow
 
 The decoding starts with an empty level that is by default in eager mode: `[[] [] [] []]`.
 
 Any input token that is not a block needs no work, therefore it is immediately put
 in the prefix as it can be output as is. We call these tokens simple tokens.
-We always token munch, which means the prfix is always in reverse order.
-We will unreverse it in the end using the `reverse!` macro.
-So if the input starts with the simple tokens `1 2 3`, the level will look like this:
-`[[] [] [3 2 1] []]`.
+We always token munch, appending the tokens to the end of the prefix, such that the prefix
+is always in the correct order. So if the input starts with the simple tokens `1 2 3`,
+the level will look like this: `[[] [] [1 2 3] []]`.
+
+Since each step of the munching is a recursive call, which counts towards the recursion limit,
+we move up to eight simple tokens to the prefix at once. A token is only simple if it is not
+a block, `!`, `::` or `@`, so we first look for the first of those among the next eight
+tokens. If there is one, we move the tokens before it to the prefix at once, except the token
+just before a `!` or `::`, which may start a macro call or a path. Otherwise, we move all eight,
+unless the ninth is a `!` or `::`.
 
 Say the rest of the input is `{4 5 6} 7 8`. The block could contain a macro call that needs
 to be eagerly expanded, so we cannot just add the block to the prefix yet. To check the contents
@@ -327,65 +670,83 @@ The levels are a stack, always using the top to decode, and putting new one on t
 The result is our decoded input becomes:
 ```
 [[] [] [] []] // Used to decode the block contents
-[[] [] [3 2 1] [7 8] {}]
+[[] [] [1 2 3] [7 8] {}]
 ```
 And our input is `4 5 6`. We can now ignore the second level (the original level) and trivially
 decode the simple tokens that were in the block:
 ```
-[[] [] [6 5 4] []]
-[[] [] [3 2 1] [7 8] {}]
+[[] [] [4 5 6] []]
+[[] [] [1 2 3] [7 8] {}]
 ```
 At this point we have no more input, which means the content of the block have been decoded
-and checked for any expansion needs. To signal that the block is done, we promote the prefix
-to the block in the second level, and pop the first level. Since the prefix is in reverse order,
-we unreverse it when putting it in the block. This is done with token munching:
-```
-[[] [] [3 2 1] [7 8] {4 5 6}]
+and checked for any expansion needs. To signal that the block is done, we pop the first level
+and put its prefix in the block of the second level. Since the block is not the input to a macro
+call, we promote it to the prefix at the same time, and take the blocks postfix and put it as
+input, such that it can be decoded:
 ```
-Since we still have no more input to decode, but we can see that we have checked a block, we
-promote the block to prefix. At the same time, we take the blocks postfix and put it as input,
-such that it can be decoded:
+[[] [] [1 2 3 {4 5 6}] []]
 ```
-[[] [] [{4 5 6} 3 2 1] []]
+Since the input after the block was just simple tokens we get:
 ```
-The block contents do not need to be reversed since the prefix is only reversed on the token tree
-level. Since the input after the block was just simple tokens we get:
-```
-[[] [] [8 7 {4 5 6} 3 2 1] []]
+[[] [] [1 2 3 {4 5 6} 7 8] []]
 ```
 Now that we have no more input, no block, and no postfix, we know we have decoded everything
-and the contents of the prefix are our result. so we output the prefix in reverse order:
-`1 2 3 4 5 6 7 8`.
+and the contents of the prefix are our result, so we output it: `1 2 3 {4 5 6} 7 8`.
 
 To see how we handle macros, say our input has a macro invocation instead of the blocks:
 `1 2 some_macro!{t1 t2} 5 6`. We start, as usual, by decoding the first simple tokens.
 ```
-[[] [] [! some_macro 2 1] []]
+[[] [] [1 2] []]
+```
+We then see that the next input is a macro invocation, i.e. a path to a macro followed by `!`
+and a block. Macro paths can have multiple segments, e.g. `some_crate::some_macro`,
+which is why we detect invocations in the input, where the path is still in the correct order.
+Some macros want their input before it is checked (see the `lazy_args` option of
+`eager_macro_rules!`), so we first call the macro with the unchecked block, prefixed by
+`@__dmutil_eager_args` and the level we would have decoded the block with:
 ```
-Note how the macro invocation is also put in the prefix. We then decode the block, checking its
-contents. When they have been checked and promoted into the block we will have:
+[[] [] [1 2] [5 6]{}[some_macro]]
 ```
-[[] [] [! some_macro 2 1] [5 6]{t1 t2}]
+If the macro accepts the input, it returns like described below, except the level still
+has the block and the path, which are then removed. Otherwise, the macro puts the level back
+on the stack and gives us the block as input. Macros declared with `eager_macro_rules!` do so
+directly with `@check_expansion`, skipping `@scan_args`, if the state is from the version
+they were declared with. If the block is empty, they instead call themselves right away
+through the path in the level, with `@__dmutil_eager`, since there is nothing to check. Like with other blocks, we decode the block,
+but the level also remembers the path to the macro.
+When the contents of the block have been checked, we put them in the block, but since the level
+contains a macro invocation, we don't promote the block to the prefix:
 ```
-At this point we would previously have promoted the block to the prefix, but we can now see
-that the prefix contains a macro invocation. Since we have checked the contents of the block,
-we know that we can safely call the macro with it. We do so, removing the invocation from the
-prefix, and removing the block. When the macro returns it will put its result as input, so the
-first thing we do is extract the postfix to the input too, putting it after the macros result,
-where it belongs. Our level will no look like:
+[[] [] [1 2] [5 6]{t1 t2}[some_macro]]
 ```
-[[] [] [2 1] []]
+Since we have checked the contents of the block, we know that we can safely call the macro
+with it. We do so, prefixed by `@__dmutil_eager` and
+the rest of the stack, removing the path and the block from the level. When the macro returns it
+will put its result as input, so the first thing we do is extract the postfix to the input too,
+putting it after the macros result, where it belongs. Our level will no look like:
+```
+[[] [] [1 2] []]
 ```
 And say the macro expands to `3 4`, we will have the input `3 4 5 6`.
 Using our previous rules, the result will be `1 2 3 4 5 6`.
 
+The state given to the macro always starts with `@__dmutil_version[1]` and the path to return to,
+e.g. `@__dmutil_version[1] [$crate::eager_internal]`, which were left out above. Since the macro
+may have been declared with another version of `dmutil`, we check the version when it returns,
+and remove both before continuing. This is done as soon as the macro returns, before anything
+else looks at the state, and a state without a version is rejected like one with another version.
+
+If the macro uses the `no_rescan` option of `eager_macro_rules!`, it returns with `@no_rescan`
+before the level. Its result must then not be checked, so we append it to the prefix
+before extracting the postfix to the input.
+
 Say we have a lazy block: `eager_macro_1!{} lazy!{ lazy_macro!{}} eager_macro_2!{}`
 Say `eager_macro_1!` expands to `1 2`, and `eager_macro_2!` expands to `3 4`, and both are
 `eager!`-enabled. `lazy_macro!` on the other hand is not `eager!`enabled.
 
 We startin the usual way, and after expanding the first macro we will have the levels:
 ```
-[[] [] [2 1] []]
+[[] [] [1 2] []]
 ```
 and the input `lazy!{ lazy_macro!{}} eager_macro_2!{}`. We can now see that the `lazy!` block
 is the opposite of the current mode (eager), so we will have to do a mode change. We take all
@@ -393,24 +754,20 @@ input that is after the `lazy!` block, and put it is modefix as it still needs t
 expanded. Then we change the mode to lazy. Lastly, we extract the content of the `lazy!` block
 and put it as input. This will result in the levels:
 ```
-[[@lazy] [eager_macro_2!{}] [2 1] []]
-```
-and the input `lazy_macro!{}`. At this point we continue as we would have previously done,
-resulting in the block being checked (trivial since its empty) and no more input:
+[[@lazy] [eager_macro_2!{}] [1 2] []]
 ```
-[[@lazy] [eager_macro_2!{}] [! lazy_macro 2 1] []{}]
+and the input `lazy_macro!{}`. In lazy mode, macro invocations are not detected, so the path
+and `!` are simple tokens, and the block is decoded like any other block. This results in
+the block being checked (trivial since its empty). Since the level does not contain a macro path,
+there is no macro to call and we just promote the block to prefix immediately:
 ```
-Previously, we would have invoked `lazy_macro!`, since its block has been checked, but this time
-we are in lazy expansion mode. Therefore, there is no need to call the macro and we just promote
-the block to prefix immediately:
-```
-[[@lazy] [eager_macro_2!{}] [{}! lazy_macro 2 1] []]
+[[@lazy] [eager_macro_2!{}] [1 2 lazy_macro!{}] []]
 ```
 At this point we have no more input and no block, but we still have something in the modefix.
 So, we extract that into input, and switch the mode, since the input in modefix always needs to
 be decoded in the opposite mode. So we get the levels:
 ```
-[[] [] [{}! lazy_macro 2 1] []]
+[[] [] [1 2 lazy_macro!{}] []]
 ```
 and input `eager_macro_2!{}`. The decoding now proceeds as previously described, resulting in
 `1 2 lazy_macro!{} 3 4` as output.
@@ -426,251 +783,3349 @@ The following are examples of the same promotion, except the input uses the diff
 
 * Promoting modefix to input (the last step above) must only be done after all other input and
 blocks have been decoded fully.
+
+* An inner attribute `#![...]` is not mistaken for a macro call, since `#` cannot be the path
+of a macro.
+
+* A `!` after a keyword is a logical not, e.g. `if !(a && b) {...}`, and must not be mistaken for
+a call to a macro named `if`. Therefore, keywords that can precede an expression are pushed
+to the prefix together with the `!` before we look for macro calls. Operators, e.g. `&& !(a)`,
+need no special handling, since they cannot be the path of a macro.
+
+* Calls to the standard library macros that are in scope by default are handled like the keywords
+before `!`: the name and the `!` are pushed to the prefix, such that the following block is
+decoded like any other. For macros that need their input as is, the block is pushed to the prefix too.
+A path starting with `std::`, `core::` or `alloc::`, with or without the leading `::`,
+is pushed to the prefix segment by segment,
+with `@__dmutil_std` before the rest of the input, marking that the macro at the end of
+the path is from the standard library, even if its name isn't known.
 */
 #[macro_export]
 #[doc(hidden)]
 macro_rules! eager_internal{
-// Handle return from eager macro expansion
-	(
-		@from_macro[
-			[$lazy:tt $modefix:tt $prefix:tt[$($postfix:tt)*]]
-			$($rest_decoded:tt)*
-		]
-		$($expanded:tt)*
-	) => {
-		eager_internal!{
-			@check_expansion[
-				[$lazy $modefix $prefix []]
-				$($rest_decoded)*
-			]
-			$($expanded)* $($postfix)*
+// Decode options
+	(	// Convert the fuel to unary, one digit at a time
+		@options $options:tt @fuel[$($value:tt)*] $($all:tt)*
+	)=>{
+		$crate::eager_unary_internal!{
+			[$($value)*] [] [fuel [$($value)*] $options]
+			$($all)*
 		}
 	};
-// Decode input stream
-	(	// If the next token is a block, check it (brace type)
-		@check_expansion[
-			[$lazy:tt $modefix:tt [$($prefix:tt)*][]]
-			$($rest_decoded:tt)*
-		]
-		{$($body:tt)*} $($rest:tt)*
+	(	// Convert the depth to unary, one digit at a time
+		@options $options:tt @depth[$($value:tt)*] $($all:tt)*
 	)=>{
-		eager_internal!{
-			@check_expansion[
-				[$lazy [][][]]
-				[$lazy $modefix [$($prefix)*][$($rest)*]{}]
-				$($rest_decoded)*
-			]
-			$($body)*
+		$crate::eager_unary_internal!{
+			[$($value)*] [] [depth [$($value)*] $options]
+			$($all)*
 		}
 	};
-	(	// If the next token is a block, check it (parenthesis type)
-		@check_expansion[
-			[$lazy:tt $modefix:tt [$($prefix:tt)*][]]
-			$($rest_decoded:tt)*
-		]
-		($($body:tt)*) $($rest:tt)*
+	(	// Only the listed macros may be expanded
+		@options $options:tt
+		@only[$($list:tt)*] $($all:tt)*
 	)=>{
-		eager_internal!{
-			@check_expansion[
-				[$lazy [][][]]
-				[$lazy $modefix [$($prefix)*][$($rest)*]()]
-				$($rest_decoded)*
-			]
-			$($body)*
+		$crate::eager_internal!{
+			@filter_list[only [] [] [$($list)*] $options]
+			$($all)*
 		}
 	};
-// eager/lazy mode changes
-	(	// If the next token is an 'eager!' macro call and we are already
-		// in eager mode, ignore it, extracting the body. (brace type)
-		@check_expansion[
-			[[]$modefix:tt[$($prefix:tt)*][]]
-			$($rest_decoded:tt)*
-		]
-		eager!{$($body:tt)*} $($rest:tt)*
+	(	// The listed macros may not be expanded
+		@options $options:tt
+		@except[$($list:tt)*] $($all:tt)*
 	)=>{
-		eager_internal!{
-			@check_expansion[
-				[[]$modefix[$($prefix)*][]]
-				$($rest_decoded)*
-			]
-			$($body)* $($rest)*
+		$crate::eager_internal!{
+			@filter_list[except [] [] [$($list)*] $options]
+			$($all)*
 		}
 	};
-	(	// If the next token is an 'eager!' macro call and we are already
-		// in eager mode, ignore it, extracting the body. (parenthesis type)
-		@check_expansion[
-			[[]$modefix:tt[$($prefix:tt)*][]]
-			$($rest_decoded:tt)*
-		]
-		eager!($($body:tt)*) $($rest:tt)*
+	(	// The result must be given to the macro
+		@options[$fuel:tt $depth:tt $filter:tt $into:tt $trace:tt]
+		@into[$first:ident $(:: $segment:ident)* !] $($all:tt)*
 	)=>{
-		eager_internal!{
-			@check_expansion[
-				[[]$modefix[$($prefix)*][]]
-				$($rest_decoded)*
-			]
-			$($body)* $($rest)*
+		$crate::eager_internal!{
+			@options[$fuel $depth $filter [@into[$first $(:: $segment)* !]] $trace]
+			$($all)*
 		}
 	};
-	(	// If the next token is an 'lazy!' macro call and we are already
-		// in lazy mode, ignore it, extracting the body. (brace type)
-		@check_expansion[
-			[[@lazy]$modefix:tt[$($prefix:tt)*][]]
-			$($rest_decoded:tt)*
-		]
-		lazy!{$($body:tt)*} $($rest:tt)*
+	(	// Same as above, but the path has a leading '::'
+		@options[$fuel:tt $depth:tt $filter:tt $into:tt $trace:tt]
+		@into[:: $first:ident $(:: $segment:ident)* !] $($all:tt)*
 	)=>{
-		eager_internal!{
-			@check_expansion[
-				[[@lazy]$modefix[$($prefix)*][]]
-				$($rest_decoded)*
-			]
-			$($body)* $($rest)*
+		$crate::eager_internal!{
+			@options[$fuel $depth $filter [@into[:: $first $(:: $segment)* !]] $trace]
+			$($all)*
 		}
 	};
-	(	// If the next token is an 'lazy!' macro call and we are already
-		// in lazy mode, ignore it, extracting the body. (parenthesis type)
-		@check_expansion[
-			[[@lazy]$modefix:tt[$($prefix:tt)*][]]
-			$($rest_decoded:tt)*
-		]
-		lazy!($($body:tt)*) $($rest:tt)*
+	(	// Otherwise, what was given is not a macro
+		@options $options:tt
+		@into[$($other:tt)*] $($all:tt)*
 	)=>{
-		eager_internal!{
-			@check_expansion[
-				[[@lazy]$modefix[$($prefix)*][]]
-				$($rest_decoded)*
-			]
-			$($body)* $($rest)*
+		compile_error!{
+			concat!(
+				"eager!: '@into' must be given the path to a macro followed by '!', ",
+				"e.g. '@into[some_crate::consume!]', but was given '", stringify!($($other)*), "'"
+			)
 		}
 	};
-	(	// If the next token is an 'eager!' macro call and we are
-		// in lazy mode (brace type)
-		@check_expansion[
-			[[@lazy][][$($prefix:tt)*][]]
-			$($rest_decoded:tt)*
+	(	// With a depth of 0 no macros may be expanded, so everything is lazy
+		@options[
+			[$($fuel:tt)*] [@depth[[]]] [$($filter:tt)*] [$($into:tt)*] [$($trace:tt)*]
 		]
-		eager!{$($body:tt)*} $($rest:tt)*
+		$($all:tt)*
 	)=>{
-		eager_internal!{
+		$crate::eager_internal!{
 			@check_expansion[
-				[[][$($rest)*][$($prefix)*][]]
-				$($rest_decoded)*
+				[[][][][]]
+				{$($filter)* $($into)* $($trace)*}
+				$($fuel)*
 			]
-			$($body)*
+			lazy!{$($all)*}
 		}
 	};
-	(	// If the next token is an 'eager!' macro call and we are
+	(	// When there are no more options and the result is neither given to a macro nor traced,
+		// the input may be several items, which are each decoded with the options
+		@options[[$($fuel:tt)*] [$($depth:tt)*] [$($filter:tt)*] [] []]
+		$($all:tt)*
+	)=>{
+		$crate::eager_internal!{
+			@start[[[][][][]] {$($depth)* $($filter)*} $($fuel)*]
+			$($all)*
+		}
+	};
+	(	// Otherwise, start decoding with the options at the bottom of the stack
+		@options[
+			[$($fuel:tt)*] [$($depth:tt)*] [$($filter:tt)*] [$($into:tt)*] [$($trace:tt)*]
+		]
+		$($all:tt)*
+	)=>{
+		$crate::eager_internal!{
+			@check_expansion[
+				[[][][][]]
+				{$($depth)* $($filter)* $($into)* $($trace)*}
+				$($fuel)*
+			]
+			$($all)*
+		}
+	};
+// end decode options
+// Use the option values converted to unary
+	(	// All digits of the fuel have been converted
+		@unary[fuel [$($unary:tt)*] [$fuel:tt $depth:tt $filter:tt $into:tt $trace:tt]] $($all:tt)*
+	)=>{
+		$crate::eager_internal!{
+			@options[[($($unary)*)] $depth $filter $into $trace]
+			$($all)*
+		}
+	};
+	(	// All digits of the depth have been converted
+		@unary[depth [$($unary:tt)*] [$fuel:tt $depth:tt $filter:tt $into:tt $trace:tt]] $($all:tt)*
+	)=>{
+		$crate::eager_internal!{
+			@options[$fuel [@depth[[$($unary)*]]] $filter $into $trace]
+			$($all)*
+		}
+	};
+// end use the option values converted to unary
+// Split the list of macros given to '@only' or '@except' into their paths
+	(	// At a comma, the path is done
+		@filter_list[
+			$kind:ident [$($entries:tt)*] [$($entry:tt)+] [, $($list:tt)*] $options:tt
+		]
+		$($all:tt)*
+	)=>{
+		$crate::eager_internal!{
+			@filter_list[$kind [$($entries)* [$($entry)+]] [] [$($list)*] $options]
+			$($all)*
+		}
+	};
+	(	// At the end of the list, so is the last path
+		@filter_list[
+			$kind:ident [$($entries:tt)*] [$($entry:tt)+] [] $options:tt
+		]
+		$($all:tt)*
+	)=>{
+		$crate::eager_internal!{
+			@filter_list[$kind [$($entries)* [$($entry)+]] [] [] $options]
+			$($all)*
+		}
+	};
+	(	// Ignore a trailing comma
+		@filter_list[
+			$kind:ident $entries:tt [] [, $($list:tt)*] $options:tt
+		]
+		$($all:tt)*
+	)=>{
+		$crate::eager_internal!{
+			@filter_list[$kind $entries [] [$($list)*] $options]
+			$($all)*
+		}
+	};
+	(	// The macros given to '@except' are compared by name, so remove the rest of the path
+		@filter_list[
+			except $entries:tt [$($entry:tt)*] [:: $($list:tt)*] $options:tt
+		]
+		$($all:tt)*
+	)=>{
+		$crate::eager_internal!{
+			@filter_list[except $entries [] [$($list)*] $options]
+			$($all)*
+		}
+	};
+	(	// Otherwise, the next token is part of the path
+		@filter_list[
+			$kind:ident $entries:tt [$($entry:tt)*] [$next:tt $($list:tt)*] $options:tt
+		]
+		$($all:tt)*
+	)=>{
+		$crate::eager_internal!{
+			@filter_list[$kind $entries [$($entry)* $next] [$($list)*] $options]
+			$($all)*
+		}
+	};
+	(	// When the list is done, continue decoding the options
+		@filter_list[
+			$kind:ident $entries:tt [] [] [$fuel:tt $depth:tt $filter:tt $into:tt $trace:tt]
+		]
+		$($all:tt)*
+	)=>{
+		$crate::eager_internal!{
+			@options[$fuel $depth [@filter[$kind $entries]] $into $trace]
+			$($all)*
+		}
+	};
+// end split the list of macros
+// Split top-level items
+	// If the input starts with a token that may start an item or statement, split it into items.
+	// Each is an arm of its own, since macro_rules can't match one of several tokens.
+	(@start $stack:tt # $($all:tt)*)=>{$crate::eager_internal!{@items $stack [] # $($all)*}};
+	(@start $stack:tt pub $($all:tt)*)=>{$crate::eager_internal!{@items $stack [] pub $($all)*}};
+	(@start $stack:tt fn $($all:tt)*)=>{$crate::eager_internal!{@items $stack [] fn $($all)*}};
+	(@start $stack:tt struct $($all:tt)*)=>{$crate::eager_internal!{@items $stack [] struct $($all)*}};
+	(@start $stack:tt enum $($all:tt)*)=>{$crate::eager_internal!{@items $stack [] enum $($all)*}};
+	(@start $stack:tt union $($all:tt)*)=>{$crate::eager_internal!{@items $stack [] union $($all)*}};
+	(@start $stack:tt impl $($all:tt)*)=>{$crate::eager_internal!{@items $stack [] impl $($all)*}};
+	(@start $stack:tt trait $($all:tt)*)=>{$crate::eager_internal!{@items $stack [] trait $($all)*}};
+	(@start $stack:tt mod $($all:tt)*)=>{$crate::eager_internal!{@items $stack [] mod $($all)*}};
+	(@start $stack:tt use $($all:tt)*)=>{$crate::eager_internal!{@items $stack [] use $($all)*}};
+	(@start $stack:tt type $($all:tt)*)=>{$crate::eager_internal!{@items $stack [] type $($all)*}};
+	(@start $stack:tt static $($all:tt)*)=>{$crate::eager_internal!{@items $stack [] static $($all)*}};
+	(@start $stack:tt const $($all:tt)*)=>{$crate::eager_internal!{@items $stack [] const $($all)*}};
+	(@start $stack:tt extern $($all:tt)*)=>{$crate::eager_internal!{@items $stack [] extern $($all)*}};
+	(@start $stack:tt unsafe $($all:tt)*)=>{$crate::eager_internal!{@items $stack [] unsafe $($all)*}};
+	(@start $stack:tt async $($all:tt)*)=>{$crate::eager_internal!{@items $stack [] async $($all)*}};
+	(@start $stack:tt let $($all:tt)*)=>{$crate::eager_internal!{@items $stack [] let $($all)*}};
+	(	// Otherwise, decode the input as a whole
+		@start[$($stack:tt)*] $($all:tt)*
+	)=>{
+		$crate::eager_internal!{
+			@check_expansion[$($stack)*]
+			$($all)*
+		}
+	};
+	// Like the decoding of simple tokens, the splitter adds the tokens before the next ';' or block
+	// to the item all at once, and only looks at what follows a block when it is the next token
+	(	// If the next token is ';', it ends the item, so decode the item on its own and split the rest
+		@items $stack:tt [$($item:tt)*] ; $($rest:tt)*
+	)=>{
+		$crate::eager_internal!{@check_expansion $stack $($item)* ;}
+		$crate::eager_internal!{@items $stack [] $($rest)*}
+	};
+	(	// If the next token is a block followed by 'else' or 'as', the item continues
+		@items $stack:tt [$($item:tt)*] {$($body:tt)*} else $($rest:tt)*
+	)=>{
+		$crate::eager_internal!{@items $stack [$($item)* {$($body)*} else] $($rest)*}
+	};
+	(
+		@items $stack:tt [$($item:tt)*] {$($body:tt)*} as $($rest:tt)*
+	)=>{
+		$crate::eager_internal!{@items $stack [$($item)* {$($body)*} as] $($rest)*}
+	};
+	(	// If the next token is a block followed by a macro call, it may continue the item
+		@items $stack:tt [$($item:tt)*] {$($body:tt)*} $name:ident ! $($rest:tt)*
+	)=>{
+		$crate::eager_internal!{@items $stack [$($item)* {$($body)*} $name !] $($rest)*}
+	};
+	(	// If the next token is a block followed by a keyword or name, the next item starts there
+		@items $stack:tt [$($item:tt)*] {$($body:tt)*} $next:ident $($rest:tt)*
+	)=>{
+		$crate::eager_internal!{@check_expansion $stack $($item)* {$($body)*}}
+		$crate::eager_internal!{@items $stack [] $next $($rest)*}
+	};
+	(	// If the next token is a block followed by an attribute, the next item starts there
+		@items $stack:tt [$($item:tt)*] {$($body:tt)*} # $($rest:tt)*
+	)=>{
+		$crate::eager_internal!{@check_expansion $stack $($item)* {$($body)*}}
+		$crate::eager_internal!{@items $stack [] # $($rest)*}
+	};
+	(	// If the next token is a block followed by anything else, the item continues
+		@items $stack:tt [$($item:tt)*] {$($body:tt)*} $($rest:tt)*
+	)=>{
+		$crate::eager_internal!{@items $stack [$($item)* {$($body)*}] $($rest)*}
+	};
+	(	// If the second token is ';', the tokens before it end the item
+		@items $stack:tt [$($item:tt)*] $t1:tt ; $($rest:tt)*
+	)=>{
+		$crate::eager_internal!{@check_expansion $stack $($item)* $t1 ;}
+		$crate::eager_internal!{@items $stack [] $($rest)*}
+	};
+	(	// If the second token is a block, add the tokens before it to the item
+		@items $stack:tt [$($item:tt)*] $t1:tt {$($body:tt)*} $($rest:tt)*
+	)=>{
+		$crate::eager_internal!{@items $stack [$($item)* $t1] {$($body)*} $($rest)*}
+	};
+	(	// If the third token is ';', the tokens before it end the item
+		@items $stack:tt [$($item:tt)*] $t1:tt $t2:tt ; $($rest:tt)*
+	)=>{
+		$crate::eager_internal!{@check_expansion $stack $($item)* $t1 $t2 ;}
+		$crate::eager_internal!{@items $stack [] $($rest)*}
+	};
+	(	// If the third token is a block, add the tokens before it to the item
+		@items $stack:tt [$($item:tt)*] $t1:tt $t2:tt {$($body:tt)*} $($rest:tt)*
+	)=>{
+		$crate::eager_internal!{@items $stack [$($item)* $t1 $t2] {$($body)*} $($rest)*}
+	};
+	(	// If the fourth token is ';', the tokens before it end the item
+		@items $stack:tt [$($item:tt)*] $t1:tt $t2:tt $t3:tt ; $($rest:tt)*
+	)=>{
+		$crate::eager_internal!{@check_expansion $stack $($item)* $t1 $t2 $t3 ;}
+		$crate::eager_internal!{@items $stack [] $($rest)*}
+	};
+	(	// If the fourth token is a block, add the tokens before it to the item
+		@items $stack:tt [$($item:tt)*] $t1:tt $t2:tt $t3:tt {$($body:tt)*} $($rest:tt)*
+	)=>{
+		$crate::eager_internal!{@items $stack [$($item)* $t1 $t2 $t3] {$($body)*} $($rest)*}
+	};
+	(	// If the fifth token is ';', the tokens before it end the item
+		@items $stack:tt [$($item:tt)*] $t1:tt $t2:tt $t3:tt $t4:tt ; $($rest:tt)*
+	)=>{
+		$crate::eager_internal!{@check_expansion $stack $($item)* $t1 $t2 $t3 $t4 ;}
+		$crate::eager_internal!{@items $stack [] $($rest)*}
+	};
+	(	// If the fifth token is a block, add the tokens before it to the item
+		@items $stack:tt [$($item:tt)*] $t1:tt $t2:tt $t3:tt $t4:tt {$($body:tt)*} $($rest:tt)*
+	)=>{
+		$crate::eager_internal!{@items $stack [$($item)* $t1 $t2 $t3 $t4] {$($body)*} $($rest)*}
+	};
+	(	// If the sixth token is ';', the tokens before it end the item
+		@items $stack:tt [$($item:tt)*] $t1:tt $t2:tt $t3:tt $t4:tt $t5:tt ; $($rest:tt)*
+	)=>{
+		$crate::eager_internal!{@check_expansion $stack $($item)* $t1 $t2 $t3 $t4 $t5 ;}
+		$crate::eager_internal!{@items $stack [] $($rest)*}
+	};
+	(	// If the sixth token is a block, add the tokens before it to the item
+		@items $stack:tt [$($item:tt)*] $t1:tt $t2:tt $t3:tt $t4:tt $t5:tt {$($body:tt)*} $($rest:tt)*
+	)=>{
+		$crate::eager_internal!{@items $stack [$($item)* $t1 $t2 $t3 $t4 $t5] {$($body)*} $($rest)*}
+	};
+	(	// If the seventh token is ';', the tokens before it end the item
+		@items $stack:tt [$($item:tt)*] $t1:tt $t2:tt $t3:tt $t4:tt $t5:tt $t6:tt ; $($rest:tt)*
+	)=>{
+		$crate::eager_internal!{@check_expansion $stack $($item)* $t1 $t2 $t3 $t4 $t5 $t6 ;}
+		$crate::eager_internal!{@items $stack [] $($rest)*}
+	};
+	(	// If the seventh token is a block, add the tokens before it to the item
+		@items $stack:tt [$($item:tt)*] $t1:tt $t2:tt $t3:tt $t4:tt $t5:tt $t6:tt {$($body:tt)*} $($rest:tt)*
+	)=>{
+		$crate::eager_internal!{@items $stack [$($item)* $t1 $t2 $t3 $t4 $t5 $t6] {$($body)*} $($rest)*}
+	};
+	(	// If the eighth token is ';', the tokens before it end the item
+		@items $stack:tt [$($item:tt)*] $t1:tt $t2:tt $t3:tt $t4:tt $t5:tt $t6:tt $t7:tt ; $($rest:tt)*
+	)=>{
+		$crate::eager_internal!{@check_expansion $stack $($item)* $t1 $t2 $t3 $t4 $t5 $t6 $t7 ;}
+		$crate::eager_internal!{@items $stack [] $($rest)*}
+	};
+	(	// If the eighth token is a block, add the tokens before it to the item
+		@items $stack:tt [$($item:tt)*] $t1:tt $t2:tt $t3:tt $t4:tt $t5:tt $t6:tt $t7:tt {$($body:tt)*} $($rest:tt)*
+	)=>{
+		$crate::eager_internal!{@items $stack [$($item)* $t1 $t2 $t3 $t4 $t5 $t6 $t7] {$($body)*} $($rest)*}
+	};
+	(	// Otherwise, none of the next eight tokens end an item,
+		// so add them to the item at once
+		@items $stack:tt [$($item:tt)*] $t1:tt $t2:tt $t3:tt $t4:tt $t5:tt $t6:tt $t7:tt $t8:tt $($rest:tt)*
+	)=>{
+		$crate::eager_internal!{@items $stack [$($item)* $t1 $t2 $t3 $t4 $t5 $t6 $t7 $t8] $($rest)*}
+	};
+	(	// When there is no more input after an item, there is nothing left to decode
+		@items $stack:tt []
+	)=>{};
+	(	// Otherwise, the rest of the input ends the last item
+		@items $stack:tt [$($item:tt)*] $($rest:tt)*
+	)=>{
+		$crate::eager_internal!{@check_expansion $stack $($item)* $($rest)*}
+	};
+// end split top-level items
+// Handle return from eager macro expansion
+	(	// If the macro returned the state of this version of the protocol,
+		// remove the version and the return path. These are the only places the version is removed,
+		// so a state from another version can't be decoded
+		@from_macro[
+			@__dmutil_version[1] $return:tt
+			$($rest_decoded:tt)*
+		]
+		$($expanded:tt)*
+	) => {
+		$crate::eager_internal!{
+			@returned[$($rest_decoded)*]
+			$($expanded)*
+		}
+	};
+	(
+		@from_macro[
+			@no_rescan @__dmutil_version[1] $return:tt
+			$($rest_decoded:tt)*
+		]
+		$($expanded:tt)*
+	) => {
+		$crate::eager_internal!{
+			@returned[@no_rescan $($rest_decoded)*]
+			$($expanded)*
+		}
+	};
+	(	// If the state is from another version of the protocol, we can't decode it
+		@from_macro[
+			$(@no_rescan)? @__dmutil_version[$($version:tt)*]
+			$($rest_decoded:tt)*
+		]
+		$($expanded:tt)*
+	) => {
+		$crate::eager_internal!{@version_mismatch[$($version)*]}
+	};
+	(	// Likewise if the state has no version, i.e. is from before the protocol was versioned
+		@from_macro[
+			$($rest_decoded:tt)*
+		]
+		$($expanded:tt)*
+	) => {
+		$crate::eager_internal!{@version_mismatch[]}
+	};
+	(	// A macro called by an earlier version of 'eager!', from before the protocol was versioned,
+		// returns the state it was given. It has no version and keeps the prefix of each level
+		// reversed, so translate it to this version before returning normally
+		@from_eager[
+			$(@$no_rescan:ident)?
+			$([$($old_level:tt)*])*
+		]
+		$($expanded:tt)*
+	) => {
+		$crate::eager_internal!{
+			@translate_eager[[$(@$no_rescan)?] [] $([$($old_level)*])*]
+			$($expanded)*
+		}
+	};
+	(	// Translate the next level by reversing its prefix
+		@translate_eager[
+			$no_rescan:tt [$($level:tt)*]
+			[$lazy:tt $modefix:tt [$($prefix:tt)*] $($old_rest:tt)*]
+			$($old_level:tt)*
+		]
+		$($expanded:tt)*
+	) => {
+		$crate::eager_internal!{
+			@reverse_eager[[$($prefix)*] [] [$lazy $modefix] [$($old_rest)*] $no_rescan [$($level)*] $($old_level)*]
+			$($expanded)*
+		}
+	};
+	(
+		@reverse_eager[[$next:tt $($prefix:tt)*] [$($reversed:tt)*] $($state:tt)*]
+		$($expanded:tt)*
+	) => {
+		$crate::eager_internal!{
+			@reverse_eager[[$($prefix)*] [$next $($reversed)*] $($state)*]
+			$($expanded)*
+		}
+	};
+	(	// When the prefix has been reversed, the level is translated
+		@reverse_eager[
+			[] $reversed:tt [$lazy:tt $modefix:tt] [$($old_rest:tt)*]
+			$no_rescan:tt [$($level:tt)*] $($old_level:tt)*
+		]
+		$($expanded:tt)*
+	) => {
+		$crate::eager_internal!{
+			@translate_eager[$no_rescan [$($level)* [$lazy $modefix $reversed $($old_rest)*]] $($old_level)*]
+			$($expanded)*
+		}
+	};
+	(	// When all levels have been translated, return normally
+		@translate_eager[[$($no_rescan:tt)*] [$($level:tt)*]]
+		$($expanded:tt)*
+	) => {
+		$crate::eager_internal!{
+			@returned[$($no_rescan)* $($level)*]
+			$($expanded)*
+		}
+	};
+	(	// If a macro asks for its input to be scanned, check the version
+		// and decode the input with the level that was given to the macro
+		@scan_args[
+			@__dmutil_version[1] $return:tt
+			$($rest_decoded:tt)*
+		]
+		$($input:tt)*
+	) => {
+		$crate::eager_internal!{
+			@check_expansion[
+				[[][][][]]
+				$($rest_decoded)*
+			]
+			$($input)*
+		}
+	};
+	(
+		@scan_args[
+			@__dmutil_version[$($version:tt)*]
+			$($rest_decoded:tt)*
+		]
+		$($input:tt)*
+	) => {
+		$crate::eager_internal!{@version_mismatch[$($version)*]}
+	};
+	(
+		@scan_args[
+			$($rest_decoded:tt)*
+		]
+		$($input:tt)*
+	) => {
+		$crate::eager_internal!{@version_mismatch[]}
+	};
+	(
+		@version_mismatch[]
+	) => {
+		compile_error!{
+			concat!(
+				"eager!: a macro was called using a version of the eager! protocol from before it ",
+				"was versioned, but was declared using version 1. ",
+				"Make sure all crates use compatible versions of dmutil"
+			)
+		}
+	};
+	(
+		@version_mismatch[$($version:tt)*]
+	) => {
+		compile_error!{
+			concat!(
+				"eager!: a macro was called using version ", stringify!($($version)*),
+				" of the eager! protocol, but was declared using version 1. ",
+				"Make sure all crates use compatible versions of dmutil"
+			)
+		}
+	};
+	(	// If there are no options, the expansion can be checked right away
+		@returned[
+			[$lazy:tt $modefix:tt $prefix:tt [$($postfix:tt)*]]
+			$([$($level:tt)*])*
+		]
+		$($expanded:tt)*
+	) => {
+		$crate::eager_internal!{
+			@check_expansion[
+				[$lazy $modefix $prefix []]
+				$([$($level)*])*
+			]
+			$($expanded)* $($postfix)*
+		}
+	};
+	(	// If the macro was given the block before it was checked,
+		// add the level to the trace if given, then return normally
+		@returned[
+			$(@$no_rescan:ident)?
+			[[] $modefix:tt $prefix:tt $postfix:tt $block:tt $macro_path:tt]
+			$([$($level:tt)*])*
+			$({
+				$(@depth $depth:tt)?
+				$(@filter $filter:tt)?
+				$(@into $into:tt)?
+				$(@trace[$($trace:tt)*])?
+			})?
+			$(($($fuel:tt)*))?
+		]
+		$($expanded:tt)*
+	) => {
+		$crate::eager_internal!{
+			@returned[
+				$(@$no_rescan)?
+				[[] $modefix $prefix $postfix]
+				$([$($level)*])*
+				$({
+					$(@depth $depth)?
+					$(@filter $filter)?
+					$(@into $into)?
+					$(@trace[
+						$($trace)*
+						stringify!([[] $modefix $prefix $postfix $block $macro_path]),
+					])?
+				})?
+				$(($($fuel)*))?
+			]
+			$($expanded)*
+		}
+	};
+	(	// If the expansion must not be checked, move it to the prefix
+		// and continue with the postfix
+		@returned[
+			@no_rescan
+			[$lazy:tt $modefix:tt [$($prefix:tt)*] [$($postfix:tt)*]]
+			$($rest_decoded:tt)*
+		]
+		$($expanded:tt)*
+	) => {
+		$crate::eager_internal!{
+			@check_expansion[
+				[$lazy $modefix [$($prefix)* $($expanded)*] []]
+				$($rest_decoded)*
+			]
+			$($postfix)*
+		}
+	};
+	(	// If the expansion is as deep as allowed, don't check it
+		@returned[
+			[$lazy:tt $modefix:tt $prefix:tt $postfix:tt]
+			$([$($level:tt)*])*
+			{@depth[[$spent:tt] $($depth:tt)*] $(@filter $filter:tt)? $(@into $into:tt)? $(@trace $trace:tt)?}
+			$(($($fuel:tt)*))?
+		]
+		$($expanded:tt)*
+	) => {
+		$crate::eager_internal!{
+			@returned[
+				@no_rescan
+				[$lazy $modefix $prefix $postfix]
+				$([$($level)*])*
+				{@depth[[$spent] $($depth)*] $(@filter $filter)? $(@into $into)? $(@trace $trace)?}
+				$(($($fuel)*))?
+			]
+			$($expanded)*
+		}
+	};
+	(	// Otherwise, check the expansion one level deeper,
+		// marking where it ends such that the depth can be restored
+		@returned[
+			[$lazy:tt $modefix:tt $prefix:tt [$($postfix:tt)*]]
+			$([$($level:tt)*])*
+			{@depth[[$spent:tt $($left:tt)+] $($depth:tt)*] $(@filter $filter:tt)? $(@into $into:tt)? $(@trace $trace:tt)?}
+			$(($($fuel:tt)*))?
+		]
+		$($expanded:tt)*
+	) => {
+		$crate::eager_internal!{
+			@check_expansion[
+				[$lazy $modefix $prefix []]
+				$([$($level)*])*
+				{
+					@depth[[$($left)+] [$spent $($left)+] $($depth)*]
+					$(@filter $filter)?
+					$(@into $into)?
+					$(@trace $trace)?
+				}
+				$(($($fuel)*))?
+			]
+			$($expanded)* @__dmutil_end_of_expansion $($postfix)*
+		}
+	};
+	(	// At the end of an expansion, restore the depth from before it
+		@check_expansion[
+			$([$($level:tt)*])*
+			{@depth[$current:tt $($depth:tt)*] $(@filter $filter:tt)? $(@into $into:tt)? $(@trace $trace:tt)?}
+			$(($($fuel:tt)*))?
+		]
+		@__dmutil_end_of_expansion $($rest:tt)*
+	) => {
+		$crate::eager_internal!{
+			@check_expansion[
+				$([$($level)*])*
+				{@depth[$($depth)*] $(@filter $filter)? $(@into $into)? $(@trace $trace)?}
+				$(($($fuel)*))?
+			]
+			$($rest)*
+		}
+	};
+	(
+		@returned[
+			[$lazy:tt $modefix:tt $prefix:tt[$($postfix:tt)*]]
+			$($rest_decoded:tt)*
+		]
+		$($expanded:tt)*
+	) => {
+		$crate::eager_internal!{
+			@check_expansion[
+				[$lazy $modefix $prefix []]
+				$($rest_decoded)*
+			]
+			$($expanded)* $($postfix)*
+		}
+	};
+// Decode input stream
+	(	// If the next token is a block, check it (brace type)
+		@check_expansion[
+			[$lazy:tt $modefix:tt [$($prefix:tt)*][]]
+			$([$($rest_decoded:tt)*])* $({$($config:tt)*})? $((. $($fuel:tt)*))?
+		]
+		{$($body:tt)*} $($rest:tt)*
+	)=>{
+		$crate::eager_internal!{
+			@check_expansion[
+				[$lazy [][][]]
+				[$lazy $modefix [$($prefix)*][$($rest)*]{}]
+				$([$($rest_decoded)*])* $({$($config)*})? $(($($fuel)*))?
+			]
+			$($body)*
+		}
+	};
+	(	// If the next token is a block, check it (parenthesis type)
+		@check_expansion[
+			[$lazy:tt $modefix:tt [$($prefix:tt)*][]]
+			$([$($rest_decoded:tt)*])* $({$($config:tt)*})? $((. $($fuel:tt)*))?
+		]
+		($($body:tt)*) $($rest:tt)*
+	)=>{
+		$crate::eager_internal!{
+			@check_expansion[
+				[$lazy [][][]]
+				[$lazy $modefix [$($prefix)*][$($rest)*]()]
+				$([$($rest_decoded)*])* $({$($config)*})? $(($($fuel)*))?
+			]
+			$($body)*
+		}
+	};
+	(	// If the next token is a block, check it (bracket type)
+		@check_expansion[
+			[$lazy:tt $modefix:tt [$($prefix:tt)*][]]
+			$([$($rest_decoded:tt)*])* $({$($config:tt)*})? $((. $($fuel:tt)*))?
+		]
+		[$($body:tt)*] $($rest:tt)*
+	)=>{
+		$crate::eager_internal!{
+			@check_expansion[
+				[$lazy [][][]]
+				[$lazy $modefix [$($prefix)*][$($rest)*][]]
+				$([$($rest_decoded)*])* $({$($config)*})? $(($($fuel)*))?
+			]
+			$($body)*
+		}
+	};
+// eager/lazy mode changes
+	(	// If the next token is an 'eager!' macro call through 'dmutil',
+		// remove the path such that it is handled like an unqualified call
+		@check_expansion[
+			$($decoded:tt)*
+		]
+		$(::)? dmutil :: eager ! $($rest:tt)*
+	)=>{
+		$crate::eager_internal!{
+			@check_expansion[
+				$($decoded)*
+			]
+			eager ! $($rest)*
+		}
+	};
+	(	// If the next token is an 'eager!' macro call through '$crate',
+		// remove the path such that it is handled like an unqualified call
+		@check_expansion[
+			$($decoded:tt)*
+		]
+		$crate :: eager ! $($rest:tt)*
+	)=>{
+		$crate::eager_internal!{
+			@check_expansion[
+				$($decoded)*
+			]
+			eager ! $($rest)*
+		}
+	};
+	(	// If the next token is an 'lazy!' macro call through 'dmutil',
+		// remove the path such that it is handled like an unqualified call
+		@check_expansion[
+			$($decoded:tt)*
+		]
+		$(::)? dmutil :: lazy ! $($rest:tt)*
+	)=>{
+		$crate::eager_internal!{
+			@check_expansion[
+				$($decoded)*
+			]
+			lazy ! $($rest)*
+		}
+	};
+	(	// If the next token is an 'lazy!' macro call through '$crate',
+		// remove the path such that it is handled like an unqualified call
+		@check_expansion[
+			$($decoded:tt)*
+		]
+		$crate :: lazy ! $($rest:tt)*
+	)=>{
+		$crate::eager_internal!{
+			@check_expansion[
+				$($decoded)*
+			]
+			lazy ! $($rest)*
+		}
+	};
+	(	// If the next token is an 'eager!' macro call and we are already
+		// in eager mode, ignore it, extracting the body. (brace type)
+		@check_expansion[
+			[[]$modefix:tt[$($prefix:tt)*][]]
+			$([$($rest_decoded:tt)*])* $({$($config:tt)*})? $((. $($fuel:tt)*))?
+		]
+		eager!{$($body:tt)*} $($rest:tt)*
+	)=>{
+		$crate::eager_internal!{
+			@check_expansion[
+				[[]$modefix[$($prefix)*][]]
+				$([$($rest_decoded)*])* $({$($config)*})? $(($($fuel)*))?
+			]
+			$($body)* $($rest)*
+		}
+	};
+	(	// If the next token is an 'eager!' macro call and we are already
+		// in eager mode, ignore it, extracting the body. (parenthesis type)
+		@check_expansion[
+			[[]$modefix:tt[$($prefix:tt)*][]]
+			$([$($rest_decoded:tt)*])* $({$($config:tt)*})? $((. $($fuel:tt)*))?
+		]
+		eager!($($body:tt)*) $($rest:tt)*
+	)=>{
+		$crate::eager_internal!{
+			@check_expansion[
+				[[]$modefix[$($prefix)*][]]
+				$([$($rest_decoded)*])* $({$($config)*})? $(($($fuel)*))?
+			]
+			$($body)* $($rest)*
+		}
+	};
+	(	// If the next token is an 'eager!' macro call and we are already
+		// in eager mode, ignore it, extracting the body. (bracket type)
+		@check_expansion[
+			[[]$modefix:tt[$($prefix:tt)*][]]
+			$([$($rest_decoded:tt)*])* $({$($config:tt)*})? $((. $($fuel:tt)*))?
+		]
+		eager![$($body:tt)*] $($rest:tt)*
+	)=>{
+		$crate::eager_internal!{
+			@check_expansion[
+				[[]$modefix[$($prefix)*][]]
+				$([$($rest_decoded)*])* $({$($config)*})? $(($($fuel)*))?
+			]
+			$($body)* $($rest)*
+		}
+	};
+	(	// If the next token is an 'lazy!' macro call and we are already
+		// in lazy mode, ignore it, extracting the body. (brace type)
+		@check_expansion[
+			[[@lazy]$modefix:tt[$($prefix:tt)*][]]
+			$([$($rest_decoded:tt)*])* $({$($config:tt)*})? $((. $($fuel:tt)*))?
+		]
+		lazy!{$($body:tt)*} $($rest:tt)*
+	)=>{
+		$crate::eager_internal!{
+			@check_expansion[
+				[[@lazy]$modefix[$($prefix)*][]]
+				$([$($rest_decoded)*])* $({$($config)*})? $(($($fuel)*))?
+			]
+			$($body)* $($rest)*
+		}
+	};
+	(	// If the next token is an 'lazy!' macro call and we are already
+		// in lazy mode, ignore it, extracting the body. (parenthesis type)
+		@check_expansion[
+			[[@lazy]$modefix:tt[$($prefix:tt)*][]]
+			$([$($rest_decoded:tt)*])* $({$($config:tt)*})? $((. $($fuel:tt)*))?
+		]
+		lazy!($($body:tt)*) $($rest:tt)*
+	)=>{
+		$crate::eager_internal!{
+			@check_expansion[
+				[[@lazy]$modefix[$($prefix)*][]]
+				$([$($rest_decoded)*])* $({$($config)*})? $(($($fuel)*))?
+			]
+			$($body)* $($rest)*
+		}
+	};
+	(	// If the next token is an 'lazy!' macro call and we are already
+		// in lazy mode, ignore it, extracting the body. (bracket type)
+		@check_expansion[
+			[[@lazy]$modefix:tt[$($prefix:tt)*][]]
+			$([$($rest_decoded:tt)*])* $({$($config:tt)*})? $((. $($fuel:tt)*))?
+		]
+		lazy![$($body:tt)*] $($rest:tt)*
+	)=>{
+		$crate::eager_internal!{
+			@check_expansion[
+				[[@lazy]$modefix[$($prefix)*][]]
+				$([$($rest_decoded)*])* $({$($config)*})? $(($($fuel)*))?
+			]
+			$($body)* $($rest)*
+		}
+	};
+	(	// If the next token is an 'eager!' macro call and we are
+		// in lazy mode (brace type)
+		@check_expansion[
+			[[@lazy][][$($prefix:tt)*][]]
+			$([$($rest_decoded:tt)*])* $({$($config:tt)*})? $((. $($fuel:tt)*))?
+		]
+		eager!{$($body:tt)*} $($rest:tt)*
+	)=>{
+		$crate::eager_internal!{
+			@check_expansion[
+				[[][$($rest)*][$($prefix)*][]]
+				$([$($rest_decoded)*])* $({$($config)*})? $(($($fuel)*))?
+			]
+			$($body)*
+		}
+	};
+	(	// If the next token is an 'eager!' macro call and we are
 		// in lazy mode, ignore it, extracting the body. (parenthesis type)
 		@check_expansion[
-			[[@lazy][][$($prefix:tt)*][]]
-			$($rest_decoded:tt)*
+			[[@lazy][][$($prefix:tt)*][]]
+			$([$($rest_decoded:tt)*])* $({$($config:tt)*})? $((. $($fuel:tt)*))?
+		]
+		eager!($($body:tt)*) $($rest:tt)*
+	)=>{
+		$crate::eager_internal!{
+			@check_expansion[
+				[[][$($rest)*][$($prefix)*][]]
+				$([$($rest_decoded)*])* $({$($config)*})? $(($($fuel)*))?
+			]
+			$($body)*
+		}
+	};
+	(	// If the next token is an 'eager!' macro call and we are
+		// in lazy mode, ignore it, extracting the body. (bracket type)
+		@check_expansion[
+			[[@lazy][][$($prefix:tt)*][]]
+			$([$($rest_decoded:tt)*])* $({$($config:tt)*})? $((. $($fuel:tt)*))?
+		]
+		eager![$($body:tt)*] $($rest:tt)*
+	)=>{
+		$crate::eager_internal!{
+			@check_expansion[
+				[[][$($rest)*][$($prefix)*][]]
+				$([$($rest_decoded)*])* $({$($config)*})? $(($($fuel)*))?
+			]
+			$($body)*
+		}
+	};
+	(	// If the next token is an 'lazy!' macro call and we are
+		// in eager mode, ignore it, extracting the body. (brace type)
+		@check_expansion[
+			[[][][$($prefix:tt)*][]]
+			$([$($rest_decoded:tt)*])* $({$($config:tt)*})? $((. $($fuel:tt)*))?
+		]
+		lazy!{$($body:tt)*} $($rest:tt)*
+	)=>{
+		$crate::eager_internal!{
+			@check_expansion[
+				[[@lazy][$($rest)*][$($prefix)*][]]
+				$([$($rest_decoded)*])* $({$($config)*})? $(($($fuel)*))?
+			]
+			$($body)*
+		}
+	};
+	(	// If the next token is an 'lazy!' macro call and we are
+		// in eager mode, ignore it, extracting the body. (parenthesis type)
+		@check_expansion[
+			[[][][$($prefix:tt)*][]]
+			$([$($rest_decoded:tt)*])* $({$($config:tt)*})? $((. $($fuel:tt)*))?
+		]
+		lazy!($($body:tt)*) $($rest:tt)*
+	)=>{
+		$crate::eager_internal!{
+			@check_expansion[
+				[[@lazy][$($rest)*][$($prefix)*][]]
+				$([$($rest_decoded)*])* $({$($config)*})? $(($($fuel)*))?
+			]
+			$($body)*
+		}
+	};
+	(	// If the next token is an 'lazy!' macro call and we are
+		// in eager mode, ignore it, extracting the body. (bracket type)
+		@check_expansion[
+			[[][][$($prefix:tt)*][]]
+			$([$($rest_decoded:tt)*])* $({$($config:tt)*})? $((. $($fuel:tt)*))?
+		]
+		lazy![$($body:tt)*] $($rest:tt)*
+	)=>{
+		$crate::eager_internal!{
+			@check_expansion[
+				[[@lazy][$($rest)*][$($prefix)*][]]
+				$([$($rest_decoded)*])* $({$($config)*})? $(($($fuel)*))?
+			]
+			$($body)*
+		}
+	};
+// end eager/lazy mode switches
+// Keywords before '!'
+	(	// 'break' is a keyword and not a macro, so the next '!' is a logical not.
+		// Push both to the prefix, such that a following block isn't seen as a call
+		@check_expansion[
+			[[] $modefix:tt [$($prefix:tt)*][]]
+			$([$($rest_decoded:tt)*])* $({$($config:tt)*})? $((. $($fuel:tt)*))?
+		]
+		break ! $($rest:tt)*
+	)=>{
+		$crate::eager_internal!{
+			@check_expansion[
+				[[] $modefix [$($prefix)* break !][]]
+				$([$($rest_decoded)*])* $({$($config)*})? $(($($fuel)*))?
+			]
+			$($rest)*
+		}
+	};
+	(	// 'box' is a keyword and not a macro, so the next '!' is a logical not.
+		// Push both to the prefix, such that a following block isn't seen as a call
+		@check_expansion[
+			[[] $modefix:tt [$($prefix:tt)*][]]
+			$([$($rest_decoded:tt)*])* $({$($config:tt)*})? $((. $($fuel:tt)*))?
+		]
+		box ! $($rest:tt)*
+	)=>{
+		$crate::eager_internal!{
+			@check_expansion[
+				[[] $modefix [$($prefix)* box !][]]
+				$([$($rest_decoded)*])* $({$($config)*})? $(($($fuel)*))?
+			]
+			$($rest)*
+		}
+	};
+	(	// 'if' is a keyword and not a macro, so the next '!' is a logical not.
+		// Push both to the prefix, such that a following block isn't seen as a call
+		@check_expansion[
+			[[] $modefix:tt [$($prefix:tt)*][]]
+			$([$($rest_decoded:tt)*])* $({$($config:tt)*})? $((. $($fuel:tt)*))?
+		]
+		if ! $($rest:tt)*
+	)=>{
+		$crate::eager_internal!{
+			@check_expansion[
+				[[] $modefix [$($prefix)* if !][]]
+				$([$($rest_decoded)*])* $({$($config)*})? $(($($fuel)*))?
+			]
+			$($rest)*
+		}
+	};
+	(	// 'in' is a keyword and not a macro, so the next '!' is a logical not.
+		// Push both to the prefix, such that a following block isn't seen as a call
+		@check_expansion[
+			[[] $modefix:tt [$($prefix:tt)*][]]
+			$([$($rest_decoded:tt)*])* $({$($config:tt)*})? $((. $($fuel:tt)*))?
+		]
+		in ! $($rest:tt)*
+	)=>{
+		$crate::eager_internal!{
+			@check_expansion[
+				[[] $modefix [$($prefix)* in !][]]
+				$([$($rest_decoded)*])* $({$($config)*})? $(($($fuel)*))?
+			]
+			$($rest)*
+		}
+	};
+	(	// 'match' is a keyword and not a macro, so the next '!' is a logical not.
+		// Push both to the prefix, such that a following block isn't seen as a call
+		@check_expansion[
+			[[] $modefix:tt [$($prefix:tt)*][]]
+			$([$($rest_decoded:tt)*])* $({$($config:tt)*})? $((. $($fuel:tt)*))?
+		]
+		match ! $($rest:tt)*
+	)=>{
+		$crate::eager_internal!{
+			@check_expansion[
+				[[] $modefix [$($prefix)* match !][]]
+				$([$($rest_decoded)*])* $({$($config)*})? $(($($fuel)*))?
+			]
+			$($rest)*
+		}
+	};
+	(	// 'mut' is a keyword and not a macro, so the next '!' is a logical not.
+		// Push both to the prefix, such that a following block isn't seen as a call
+		@check_expansion[
+			[[] $modefix:tt [$($prefix:tt)*][]]
+			$([$($rest_decoded:tt)*])* $({$($config:tt)*})? $((. $($fuel:tt)*))?
+		]
+		mut ! $($rest:tt)*
+	)=>{
+		$crate::eager_internal!{
+			@check_expansion[
+				[[] $modefix [$($prefix)* mut !][]]
+				$([$($rest_decoded)*])* $({$($config)*})? $(($($fuel)*))?
+			]
+			$($rest)*
+		}
+	};
+	(	// 'return' is a keyword and not a macro, so the next '!' is a logical not.
+		// Push both to the prefix, such that a following block isn't seen as a call
+		@check_expansion[
+			[[] $modefix:tt [$($prefix:tt)*][]]
+			$([$($rest_decoded:tt)*])* $({$($config:tt)*})? $((. $($fuel:tt)*))?
+		]
+		return ! $($rest:tt)*
+	)=>{
+		$crate::eager_internal!{
+			@check_expansion[
+				[[] $modefix [$($prefix)* return !][]]
+				$([$($rest_decoded)*])* $({$($config)*})? $(($($fuel)*))?
+			]
+			$($rest)*
+		}
+	};
+	(	// 'while' is a keyword and not a macro, so the next '!' is a logical not.
+		// Push both to the prefix, such that a following block isn't seen as a call
+		@check_expansion[
+			[[] $modefix:tt [$($prefix:tt)*][]]
+			$([$($rest_decoded:tt)*])* $({$($config:tt)*})? $((. $($fuel:tt)*))?
+		]
+		while ! $($rest:tt)*
+	)=>{
+		$crate::eager_internal!{
+			@check_expansion[
+				[[] $modefix [$($prefix)* while !][]]
+				$([$($rest_decoded)*])* $({$($config)*})? $(($($fuel)*))?
+			]
+			$($rest)*
+		}
+	};
+	(	// 'yield' is a keyword and not a macro, so the next '!' is a logical not.
+		// Push both to the prefix, such that a following block isn't seen as a call
+		@check_expansion[
+			[[] $modefix:tt [$($prefix:tt)*][]]
+			$([$($rest_decoded:tt)*])* $({$($config:tt)*})? $((. $($fuel:tt)*))?
+		]
+		yield ! $($rest:tt)*
+	)=>{
+		$crate::eager_internal!{
+			@check_expansion[
+				[[] $modefix [$($prefix)* yield !][]]
+				$([$($rest_decoded)*])* $({$($config)*})? $(($($fuel)*))?
+			]
+			$($rest)*
+		}
+	};
+// end keywords before '!'
+// Standard library macros
+	(	// A path starting with '::std::' can only be to a standard library macro.
+		// Push it to the prefix, marking that the rest of the path is in the standard library
+		@check_expansion[
+			[[] $modefix:tt [$($prefix:tt)*][]]
+			$([$($rest_decoded:tt)*])* $({$($config:tt)*})? $((. $($fuel:tt)*))?
+		]
+		:: std :: $($rest:tt)*
+	)=>{
+		$crate::eager_internal!{
+			@check_expansion[
+				[[] $modefix [$($prefix)* :: std ::][]]
+				$([$($rest_decoded)*])* $({$($config)*})? $(($($fuel)*))?
+			]
+			@__dmutil_std $($rest)*
+		}
+	};
+	(	// A path starting with '::core::' can only be to a standard library macro.
+		// Push it to the prefix, marking that the rest of the path is in the standard library
+		@check_expansion[
+			[[] $modefix:tt [$($prefix:tt)*][]]
+			$([$($rest_decoded:tt)*])* $({$($config:tt)*})? $((. $($fuel:tt)*))?
+		]
+		:: core :: $($rest:tt)*
+	)=>{
+		$crate::eager_internal!{
+			@check_expansion[
+				[[] $modefix [$($prefix)* :: core ::][]]
+				$([$($rest_decoded)*])* $({$($config)*})? $(($($fuel)*))?
+			]
+			@__dmutil_std $($rest)*
+		}
+	};
+	(	// A path starting with '::alloc::' can only be to a standard library macro.
+		// Push it to the prefix, marking that the rest of the path is in the standard library
+		@check_expansion[
+			[[] $modefix:tt [$($prefix:tt)*][]]
+			$([$($rest_decoded:tt)*])* $({$($config:tt)*})? $((. $($fuel:tt)*))?
+		]
+		:: alloc :: $($rest:tt)*
+	)=>{
+		$crate::eager_internal!{
+			@check_expansion[
+				[[] $modefix [$($prefix)* :: alloc ::][]]
+				$([$($rest_decoded)*])* $({$($config)*})? $(($($fuel)*))?
+			]
+			@__dmutil_std $($rest)*
+		}
+	};
+	(	// A path starting with 'std::' is taken to be to a standard library macro too,
+		// even though a local item could be named 'std'
+		@check_expansion[
+			[[] $modefix:tt [$($prefix:tt)*][]]
+			$([$($rest_decoded:tt)*])* $({$($config:tt)*})? $((. $($fuel:tt)*))?
+		]
+		std :: $($rest:tt)*
+	)=>{
+		$crate::eager_internal!{
+			@check_expansion[
+				[[] $modefix [$($prefix)* std ::][]]
+				$([$($rest_decoded)*])* $({$($config)*})? $(($($fuel)*))?
+			]
+			@__dmutil_std $($rest)*
+		}
+	};
+	(	// A path starting with 'core::' is taken to be to a standard library macro too,
+		// even though a local item could be named 'core'
+		@check_expansion[
+			[[] $modefix:tt [$($prefix:tt)*][]]
+			$([$($rest_decoded:tt)*])* $({$($config:tt)*})? $((. $($fuel:tt)*))?
+		]
+		core :: $($rest:tt)*
+	)=>{
+		$crate::eager_internal!{
+			@check_expansion[
+				[[] $modefix [$($prefix)* core ::][]]
+				$([$($rest_decoded)*])* $({$($config)*})? $(($($fuel)*))?
+			]
+			@__dmutil_std $($rest)*
+		}
+	};
+	(	// A path starting with 'alloc::' is taken to be to a standard library macro too,
+		// even though a local item could be named 'alloc'
+		@check_expansion[
+			[[] $modefix:tt [$($prefix:tt)*][]]
+			$([$($rest_decoded:tt)*])* $({$($config:tt)*})? $((. $($fuel:tt)*))?
+		]
+		alloc :: $($rest:tt)*
+	)=>{
+		$crate::eager_internal!{
+			@check_expansion[
+				[[] $modefix [$($prefix)* alloc ::][]]
+				$([$($rest_decoded)*])* $({$($config)*})? $(($($fuel)*))?
+			]
+			@__dmutil_std $($rest)*
+		}
+	};
+	(	// Push the rest of the path to the prefix
+		@check_expansion[
+			[[] $modefix:tt [$($prefix:tt)*][]]
+			$([$($rest_decoded:tt)*])* $({$($config:tt)*})? $((. $($fuel:tt)*))?
+		]
+		@__dmutil_std $segment:ident :: $($rest:tt)*
+	)=>{
+		$crate::eager_internal!{
+			@check_expansion[
+				[[] $modefix [$($prefix)* $segment ::][]]
+				$([$($rest_decoded)*])* $({$($config)*})? $(($($fuel)*))?
+			]
+			@__dmutil_std $($rest)*
+		}
+	};
+	(	// 'asm!' is a standard library macro that needs its input as is.
+		// Push the call to the prefix without checking its input
+		@check_expansion[
+			[[] $modefix:tt [$($prefix:tt)*][]]
+			$([$($rest_decoded:tt)*])* $({$($config:tt)*})? $((. $($fuel:tt)*))?
+		]
+		$(@__dmutil_std)? asm ! $args:tt $($rest:tt)*
+	)=>{
+		$crate::eager_internal!{
+			@check_expansion[
+				[[] $modefix [$($prefix)* asm ! $args][]]
+				$([$($rest_decoded)*])* $({$($config)*})? $(($($fuel)*))?
+			]
+			$($rest)*
+		}
+	};
+	(	// 'cfg!' is a standard library macro that needs its input as is.
+		// Push the call to the prefix without checking its input
+		@check_expansion[
+			[[] $modefix:tt [$($prefix:tt)*][]]
+			$([$($rest_decoded:tt)*])* $({$($config:tt)*})? $((. $($fuel:tt)*))?
+		]
+		$(@__dmutil_std)? cfg ! $args:tt $($rest:tt)*
+	)=>{
+		$crate::eager_internal!{
+			@check_expansion[
+				[[] $modefix [$($prefix)* cfg ! $args][]]
+				$([$($rest_decoded)*])* $({$($config)*})? $(($($fuel)*))?
+			]
+			$($rest)*
+		}
+	};
+	(	// 'column!' is a standard library macro that needs its input as is.
+		// Push the call to the prefix without checking its input
+		@check_expansion[
+			[[] $modefix:tt [$($prefix:tt)*][]]
+			$([$($rest_decoded:tt)*])* $({$($config:tt)*})? $((. $($fuel:tt)*))?
+		]
+		$(@__dmutil_std)? column ! $args:tt $($rest:tt)*
+	)=>{
+		$crate::eager_internal!{
+			@check_expansion[
+				[[] $modefix [$($prefix)* column ! $args][]]
+				$([$($rest_decoded)*])* $({$($config)*})? $(($($fuel)*))?
+			]
+			$($rest)*
+		}
+	};
+	(	// 'concat_idents!' is a standard library macro that needs its input as is.
+		// Push the call to the prefix without checking its input
+		@check_expansion[
+			[[] $modefix:tt [$($prefix:tt)*][]]
+			$([$($rest_decoded:tt)*])* $({$($config:tt)*})? $((. $($fuel:tt)*))?
+		]
+		$(@__dmutil_std)? concat_idents ! $args:tt $($rest:tt)*
+	)=>{
+		$crate::eager_internal!{
+			@check_expansion[
+				[[] $modefix [$($prefix)* concat_idents ! $args][]]
+				$([$($rest_decoded)*])* $({$($config)*})? $(($($fuel)*))?
+			]
+			$($rest)*
+		}
+	};
+	(	// 'env!' is a standard library macro that needs its input as is.
+		// Push the call to the prefix without checking its input
+		@check_expansion[
+			[[] $modefix:tt [$($prefix:tt)*][]]
+			$([$($rest_decoded:tt)*])* $({$($config:tt)*})? $((. $($fuel:tt)*))?
+		]
+		$(@__dmutil_std)? env ! $args:tt $($rest:tt)*
+	)=>{
+		$crate::eager_internal!{
+			@check_expansion[
+				[[] $modefix [$($prefix)* env ! $args][]]
+				$([$($rest_decoded)*])* $({$($config)*})? $(($($fuel)*))?
+			]
+			$($rest)*
+		}
+	};
+	(	// 'file!' is a standard library macro that needs its input as is.
+		// Push the call to the prefix without checking its input
+		@check_expansion[
+			[[] $modefix:tt [$($prefix:tt)*][]]
+			$([$($rest_decoded:tt)*])* $({$($config:tt)*})? $((. $($fuel:tt)*))?
+		]
+		$(@__dmutil_std)? file ! $args:tt $($rest:tt)*
+	)=>{
+		$crate::eager_internal!{
+			@check_expansion[
+				[[] $modefix [$($prefix)* file ! $args][]]
+				$([$($rest_decoded)*])* $({$($config)*})? $(($($fuel)*))?
+			]
+			$($rest)*
+		}
+	};
+	(	// 'global_asm!' is a standard library macro that needs its input as is.
+		// Push the call to the prefix without checking its input
+		@check_expansion[
+			[[] $modefix:tt [$($prefix:tt)*][]]
+			$([$($rest_decoded:tt)*])* $({$($config:tt)*})? $((. $($fuel:tt)*))?
+		]
+		$(@__dmutil_std)? global_asm ! $args:tt $($rest:tt)*
+	)=>{
+		$crate::eager_internal!{
+			@check_expansion[
+				[[] $modefix [$($prefix)* global_asm ! $args][]]
+				$([$($rest_decoded)*])* $({$($config)*})? $(($($fuel)*))?
+			]
+			$($rest)*
+		}
+	};
+	(	// 'include!' is a standard library macro that needs its input as is.
+		// Push the call to the prefix without checking its input
+		@check_expansion[
+			[[] $modefix:tt [$($prefix:tt)*][]]
+			$([$($rest_decoded:tt)*])* $({$($config:tt)*})? $((. $($fuel:tt)*))?
+		]
+		$(@__dmutil_std)? include ! $args:tt $($rest:tt)*
+	)=>{
+		$crate::eager_internal!{
+			@check_expansion[
+				[[] $modefix [$($prefix)* include ! $args][]]
+				$([$($rest_decoded)*])* $({$($config)*})? $(($($fuel)*))?
+			]
+			$($rest)*
+		}
+	};
+	(	// 'include_bytes!' is a standard library macro that needs its input as is.
+		// Push the call to the prefix without checking its input
+		@check_expansion[
+			[[] $modefix:tt [$($prefix:tt)*][]]
+			$([$($rest_decoded:tt)*])* $({$($config:tt)*})? $((. $($fuel:tt)*))?
+		]
+		$(@__dmutil_std)? include_bytes ! $args:tt $($rest:tt)*
+	)=>{
+		$crate::eager_internal!{
+			@check_expansion[
+				[[] $modefix [$($prefix)* include_bytes ! $args][]]
+				$([$($rest_decoded)*])* $({$($config)*})? $(($($fuel)*))?
+			]
+			$($rest)*
+		}
+	};
+	(	// 'include_str!' is a standard library macro that needs its input as is.
+		// Push the call to the prefix without checking its input
+		@check_expansion[
+			[[] $modefix:tt [$($prefix:tt)*][]]
+			$([$($rest_decoded:tt)*])* $({$($config:tt)*})? $((. $($fuel:tt)*))?
+		]
+		$(@__dmutil_std)? include_str ! $args:tt $($rest:tt)*
+	)=>{
+		$crate::eager_internal!{
+			@check_expansion[
+				[[] $modefix [$($prefix)* include_str ! $args][]]
+				$([$($rest_decoded)*])* $({$($config)*})? $(($($fuel)*))?
+			]
+			$($rest)*
+		}
+	};
+	(	// 'line!' is a standard library macro that needs its input as is.
+		// Push the call to the prefix without checking its input
+		@check_expansion[
+			[[] $modefix:tt [$($prefix:tt)*][]]
+			$([$($rest_decoded:tt)*])* $({$($config:tt)*})? $((. $($fuel:tt)*))?
+		]
+		$(@__dmutil_std)? line ! $args:tt $($rest:tt)*
+	)=>{
+		$crate::eager_internal!{
+			@check_expansion[
+				[[] $modefix [$($prefix)* line ! $args][]]
+				$([$($rest_decoded)*])* $({$($config)*})? $(($($fuel)*))?
+			]
+			$($rest)*
+		}
+	};
+	(	// 'log_syntax!' is a standard library macro that needs its input as is.
+		// Push the call to the prefix without checking its input
+		@check_expansion[
+			[[] $modefix:tt [$($prefix:tt)*][]]
+			$([$($rest_decoded:tt)*])* $({$($config:tt)*})? $((. $($fuel:tt)*))?
+		]
+		$(@__dmutil_std)? log_syntax ! $args:tt $($rest:tt)*
+	)=>{
+		$crate::eager_internal!{
+			@check_expansion[
+				[[] $modefix [$($prefix)* log_syntax ! $args][]]
+				$([$($rest_decoded)*])* $({$($config)*})? $(($($fuel)*))?
+			]
+			$($rest)*
+		}
+	};
+	(	// 'module_path!' is a standard library macro that needs its input as is.
+		// Push the call to the prefix without checking its input
+		@check_expansion[
+			[[] $modefix:tt [$($prefix:tt)*][]]
+			$([$($rest_decoded:tt)*])* $({$($config:tt)*})? $((. $($fuel:tt)*))?
+		]
+		$(@__dmutil_std)? module_path ! $args:tt $($rest:tt)*
+	)=>{
+		$crate::eager_internal!{
+			@check_expansion[
+				[[] $modefix [$($prefix)* module_path ! $args][]]
+				$([$($rest_decoded)*])* $({$($config)*})? $(($($fuel)*))?
+			]
+			$($rest)*
+		}
+	};
+	(	// 'option_env!' is a standard library macro that needs its input as is.
+		// Push the call to the prefix without checking its input
+		@check_expansion[
+			[[] $modefix:tt [$($prefix:tt)*][]]
+			$([$($rest_decoded:tt)*])* $({$($config:tt)*})? $((. $($fuel:tt)*))?
+		]
+		$(@__dmutil_std)? option_env ! $args:tt $($rest:tt)*
+	)=>{
+		$crate::eager_internal!{
+			@check_expansion[
+				[[] $modefix [$($prefix)* option_env ! $args][]]
+				$([$($rest_decoded)*])* $({$($config)*})? $(($($fuel)*))?
+			]
+			$($rest)*
+		}
+	};
+	(	// 'stringify!' is a standard library macro that needs its input as is.
+		// Push the call to the prefix without checking its input
+		@check_expansion[
+			[[] $modefix:tt [$($prefix:tt)*][]]
+			$([$($rest_decoded:tt)*])* $({$($config:tt)*})? $((. $($fuel:tt)*))?
+		]
+		$(@__dmutil_std)? stringify ! $args:tt $($rest:tt)*
+	)=>{
+		$crate::eager_internal!{
+			@check_expansion[
+				[[] $modefix [$($prefix)* stringify ! $args][]]
+				$([$($rest_decoded)*])* $({$($config)*})? $(($($fuel)*))?
+			]
+			$($rest)*
+		}
+	};
+	(	// 'trace_macros!' is a standard library macro that needs its input as is.
+		// Push the call to the prefix without checking its input
+		@check_expansion[
+			[[] $modefix:tt [$($prefix:tt)*][]]
+			$([$($rest_decoded:tt)*])* $({$($config:tt)*})? $((. $($fuel:tt)*))?
+		]
+		$(@__dmutil_std)? trace_macros ! $args:tt $($rest:tt)*
+	)=>{
+		$crate::eager_internal!{
+			@check_expansion[
+				[[] $modefix [$($prefix)* trace_macros ! $args][]]
+				$([$($rest_decoded)*])* $({$($config)*})? $(($($fuel)*))?
+			]
+			$($rest)*
+		}
+	};
+	(	// 'assert!' is a standard library macro, so it can't be called eagerly.
+		// Push it to the prefix, such that its input is checked like any other block
+		@check_expansion[
+			[[] $modefix:tt [$($prefix:tt)*][]]
+			$([$($rest_decoded:tt)*])* $({$($config:tt)*})? $((. $($fuel:tt)*))?
+		]
+		$(@__dmutil_std)? assert ! $($rest:tt)*
+	)=>{
+		$crate::eager_internal!{
+			@check_expansion[
+				[[] $modefix [$($prefix)* assert !][]]
+				$([$($rest_decoded)*])* $({$($config)*})? $(($($fuel)*))?
+			]
+			$($rest)*
+		}
+	};
+	(	// 'assert_eq!' is a standard library macro, so it can't be called eagerly.
+		// Push it to the prefix, such that its input is checked like any other block
+		@check_expansion[
+			[[] $modefix:tt [$($prefix:tt)*][]]
+			$([$($rest_decoded:tt)*])* $({$($config:tt)*})? $((. $($fuel:tt)*))?
+		]
+		$(@__dmutil_std)? assert_eq ! $($rest:tt)*
+	)=>{
+		$crate::eager_internal!{
+			@check_expansion[
+				[[] $modefix [$($prefix)* assert_eq !][]]
+				$([$($rest_decoded)*])* $({$($config)*})? $(($($fuel)*))?
+			]
+			$($rest)*
+		}
+	};
+	(	// 'assert_ne!' is a standard library macro, so it can't be called eagerly.
+		// Push it to the prefix, such that its input is checked like any other block
+		@check_expansion[
+			[[] $modefix:tt [$($prefix:tt)*][]]
+			$([$($rest_decoded:tt)*])* $({$($config:tt)*})? $((. $($fuel:tt)*))?
+		]
+		$(@__dmutil_std)? assert_ne ! $($rest:tt)*
+	)=>{
+		$crate::eager_internal!{
+			@check_expansion[
+				[[] $modefix [$($prefix)* assert_ne !][]]
+				$([$($rest_decoded)*])* $({$($config)*})? $(($($fuel)*))?
+			]
+			$($rest)*
+		}
+	};
+	(	// 'compile_error!' is a standard library macro, so it can't be called eagerly.
+		// Push it to the prefix, such that its input is checked like any other block
+		@check_expansion[
+			[[] $modefix:tt [$($prefix:tt)*][]]
+			$([$($rest_decoded:tt)*])* $({$($config:tt)*})? $((. $($fuel:tt)*))?
+		]
+		$(@__dmutil_std)? compile_error ! $($rest:tt)*
+	)=>{
+		$crate::eager_internal!{
+			@check_expansion[
+				[[] $modefix [$($prefix)* compile_error !][]]
+				$([$($rest_decoded)*])* $({$($config)*})? $(($($fuel)*))?
+			]
+			$($rest)*
+		}
+	};
+	(	// 'concat!' is a standard library macro, so it can't be called eagerly.
+		// Push it to the prefix, such that its input is checked like any other block
+		@check_expansion[
+			[[] $modefix:tt [$($prefix:tt)*][]]
+			$([$($rest_decoded:tt)*])* $({$($config:tt)*})? $((. $($fuel:tt)*))?
+		]
+		$(@__dmutil_std)? concat ! $($rest:tt)*
+	)=>{
+		$crate::eager_internal!{
+			@check_expansion[
+				[[] $modefix [$($prefix)* concat !][]]
+				$([$($rest_decoded)*])* $({$($config)*})? $(($($fuel)*))?
+			]
+			$($rest)*
+		}
+	};
+	(	// 'dbg!' is a standard library macro, so it can't be called eagerly.
+		// Push it to the prefix, such that its input is checked like any other block
+		@check_expansion[
+			[[] $modefix:tt [$($prefix:tt)*][]]
+			$([$($rest_decoded:tt)*])* $({$($config:tt)*})? $((. $($fuel:tt)*))?
+		]
+		$(@__dmutil_std)? dbg ! $($rest:tt)*
+	)=>{
+		$crate::eager_internal!{
+			@check_expansion[
+				[[] $modefix [$($prefix)* dbg !][]]
+				$([$($rest_decoded)*])* $({$($config)*})? $(($($fuel)*))?
+			]
+			$($rest)*
+		}
+	};
+	(	// 'debug_assert!' is a standard library macro, so it can't be called eagerly.
+		// Push it to the prefix, such that its input is checked like any other block
+		@check_expansion[
+			[[] $modefix:tt [$($prefix:tt)*][]]
+			$([$($rest_decoded:tt)*])* $({$($config:tt)*})? $((. $($fuel:tt)*))?
+		]
+		$(@__dmutil_std)? debug_assert ! $($rest:tt)*
+	)=>{
+		$crate::eager_internal!{
+			@check_expansion[
+				[[] $modefix [$($prefix)* debug_assert !][]]
+				$([$($rest_decoded)*])* $({$($config)*})? $(($($fuel)*))?
+			]
+			$($rest)*
+		}
+	};
+	(	// 'debug_assert_eq!' is a standard library macro, so it can't be called eagerly.
+		// Push it to the prefix, such that its input is checked like any other block
+		@check_expansion[
+			[[] $modefix:tt [$($prefix:tt)*][]]
+			$([$($rest_decoded:tt)*])* $({$($config:tt)*})? $((. $($fuel:tt)*))?
+		]
+		$(@__dmutil_std)? debug_assert_eq ! $($rest:tt)*
+	)=>{
+		$crate::eager_internal!{
+			@check_expansion[
+				[[] $modefix [$($prefix)* debug_assert_eq !][]]
+				$([$($rest_decoded)*])* $({$($config)*})? $(($($fuel)*))?
+			]
+			$($rest)*
+		}
+	};
+	(	// 'debug_assert_ne!' is a standard library macro, so it can't be called eagerly.
+		// Push it to the prefix, such that its input is checked like any other block
+		@check_expansion[
+			[[] $modefix:tt [$($prefix:tt)*][]]
+			$([$($rest_decoded:tt)*])* $({$($config:tt)*})? $((. $($fuel:tt)*))?
+		]
+		$(@__dmutil_std)? debug_assert_ne ! $($rest:tt)*
+	)=>{
+		$crate::eager_internal!{
+			@check_expansion[
+				[[] $modefix [$($prefix)* debug_assert_ne !][]]
+				$([$($rest_decoded)*])* $({$($config)*})? $(($($fuel)*))?
+			]
+			$($rest)*
+		}
+	};
+	(	// 'eprint!' is a standard library macro, so it can't be called eagerly.
+		// Push it to the prefix, such that its input is checked like any other block
+		@check_expansion[
+			[[] $modefix:tt [$($prefix:tt)*][]]
+			$([$($rest_decoded:tt)*])* $({$($config:tt)*})? $((. $($fuel:tt)*))?
+		]
+		$(@__dmutil_std)? eprint ! $($rest:tt)*
+	)=>{
+		$crate::eager_internal!{
+			@check_expansion[
+				[[] $modefix [$($prefix)* eprint !][]]
+				$([$($rest_decoded)*])* $({$($config)*})? $(($($fuel)*))?
+			]
+			$($rest)*
+		}
+	};
+	(	// 'eprintln!' is a standard library macro, so it can't be called eagerly.
+		// Push it to the prefix, such that its input is checked like any other block
+		@check_expansion[
+			[[] $modefix:tt [$($prefix:tt)*][]]
+			$([$($rest_decoded:tt)*])* $({$($config:tt)*})? $((. $($fuel:tt)*))?
+		]
+		$(@__dmutil_std)? eprintln ! $($rest:tt)*
+	)=>{
+		$crate::eager_internal!{
+			@check_expansion[
+				[[] $modefix [$($prefix)* eprintln !][]]
+				$([$($rest_decoded)*])* $({$($config)*})? $(($($fuel)*))?
+			]
+			$($rest)*
+		}
+	};
+	(	// 'format!' is a standard library macro, so it can't be called eagerly.
+		// Push it to the prefix, such that its input is checked like any other block
+		@check_expansion[
+			[[] $modefix:tt [$($prefix:tt)*][]]
+			$([$($rest_decoded:tt)*])* $({$($config:tt)*})? $((. $($fuel:tt)*))?
+		]
+		$(@__dmutil_std)? format ! $($rest:tt)*
+	)=>{
+		$crate::eager_internal!{
+			@check_expansion[
+				[[] $modefix [$($prefix)* format !][]]
+				$([$($rest_decoded)*])* $({$($config)*})? $(($($fuel)*))?
+			]
+			$($rest)*
+		}
+	};
+	(	// 'format_args!' is a standard library macro, so it can't be called eagerly.
+		// Push it to the prefix, such that its input is checked like any other block
+		@check_expansion[
+			[[] $modefix:tt [$($prefix:tt)*][]]
+			$([$($rest_decoded:tt)*])* $({$($config:tt)*})? $((. $($fuel:tt)*))?
+		]
+		$(@__dmutil_std)? format_args ! $($rest:tt)*
+	)=>{
+		$crate::eager_internal!{
+			@check_expansion[
+				[[] $modefix [$($prefix)* format_args !][]]
+				$([$($rest_decoded)*])* $({$($config)*})? $(($($fuel)*))?
+			]
+			$($rest)*
+		}
+	};
+	(	// 'matches!' is a standard library macro, so it can't be called eagerly.
+		// Push it to the prefix, such that its input is checked like any other block
+		@check_expansion[
+			[[] $modefix:tt [$($prefix:tt)*][]]
+			$([$($rest_decoded:tt)*])* $({$($config:tt)*})? $((. $($fuel:tt)*))?
+		]
+		$(@__dmutil_std)? matches ! $($rest:tt)*
+	)=>{
+		$crate::eager_internal!{
+			@check_expansion[
+				[[] $modefix [$($prefix)* matches !][]]
+				$([$($rest_decoded)*])* $({$($config)*})? $(($($fuel)*))?
+			]
+			$($rest)*
+		}
+	};
+	(	// 'panic!' is a standard library macro, so it can't be called eagerly.
+		// Push it to the prefix, such that its input is checked like any other block
+		@check_expansion[
+			[[] $modefix:tt [$($prefix:tt)*][]]
+			$([$($rest_decoded:tt)*])* $({$($config:tt)*})? $((. $($fuel:tt)*))?
+		]
+		$(@__dmutil_std)? panic ! $($rest:tt)*
+	)=>{
+		$crate::eager_internal!{
+			@check_expansion[
+				[[] $modefix [$($prefix)* panic !][]]
+				$([$($rest_decoded)*])* $({$($config)*})? $(($($fuel)*))?
+			]
+			$($rest)*
+		}
+	};
+	(	// 'print!' is a standard library macro, so it can't be called eagerly.
+		// Push it to the prefix, such that its input is checked like any other block
+		@check_expansion[
+			[[] $modefix:tt [$($prefix:tt)*][]]
+			$([$($rest_decoded:tt)*])* $({$($config:tt)*})? $((. $($fuel:tt)*))?
+		]
+		$(@__dmutil_std)? print ! $($rest:tt)*
+	)=>{
+		$crate::eager_internal!{
+			@check_expansion[
+				[[] $modefix [$($prefix)* print !][]]
+				$([$($rest_decoded)*])* $({$($config)*})? $(($($fuel)*))?
+			]
+			$($rest)*
+		}
+	};
+	(	// 'println!' is a standard library macro, so it can't be called eagerly.
+		// Push it to the prefix, such that its input is checked like any other block
+		@check_expansion[
+			[[] $modefix:tt [$($prefix:tt)*][]]
+			$([$($rest_decoded:tt)*])* $({$($config:tt)*})? $((. $($fuel:tt)*))?
+		]
+		$(@__dmutil_std)? println ! $($rest:tt)*
+	)=>{
+		$crate::eager_internal!{
+			@check_expansion[
+				[[] $modefix [$($prefix)* println !][]]
+				$([$($rest_decoded)*])* $({$($config)*})? $(($($fuel)*))?
+			]
+			$($rest)*
+		}
+	};
+	(	// 'thread_local!' is a standard library macro, so it can't be called eagerly.
+		// Push it to the prefix, such that its input is checked like any other block
+		@check_expansion[
+			[[] $modefix:tt [$($prefix:tt)*][]]
+			$([$($rest_decoded:tt)*])* $({$($config:tt)*})? $((. $($fuel:tt)*))?
+		]
+		$(@__dmutil_std)? thread_local ! $($rest:tt)*
+	)=>{
+		$crate::eager_internal!{
+			@check_expansion[
+				[[] $modefix [$($prefix)* thread_local !][]]
+				$([$($rest_decoded)*])* $({$($config)*})? $(($($fuel)*))?
+			]
+			$($rest)*
+		}
+	};
+	(	// 'todo!' is a standard library macro, so it can't be called eagerly.
+		// Push it to the prefix, such that its input is checked like any other block
+		@check_expansion[
+			[[] $modefix:tt [$($prefix:tt)*][]]
+			$([$($rest_decoded:tt)*])* $({$($config:tt)*})? $((. $($fuel:tt)*))?
+		]
+		$(@__dmutil_std)? todo ! $($rest:tt)*
+	)=>{
+		$crate::eager_internal!{
+			@check_expansion[
+				[[] $modefix [$($prefix)* todo !][]]
+				$([$($rest_decoded)*])* $({$($config)*})? $(($($fuel)*))?
+			]
+			$($rest)*
+		}
+	};
+	(	// 'try!' is a standard library macro, so it can't be called eagerly.
+		// Push it to the prefix, such that its input is checked like any other block
+		@check_expansion[
+			[[] $modefix:tt [$($prefix:tt)*][]]
+			$([$($rest_decoded:tt)*])* $({$($config:tt)*})? $((. $($fuel:tt)*))?
+		]
+		$(@__dmutil_std)? try ! $($rest:tt)*
+	)=>{
+		$crate::eager_internal!{
+			@check_expansion[
+				[[] $modefix [$($prefix)* try !][]]
+				$([$($rest_decoded)*])* $({$($config)*})? $(($($fuel)*))?
+			]
+			$($rest)*
+		}
+	};
+	(	// 'unimplemented!' is a standard library macro, so it can't be called eagerly.
+		// Push it to the prefix, such that its input is checked like any other block
+		@check_expansion[
+			[[] $modefix:tt [$($prefix:tt)*][]]
+			$([$($rest_decoded:tt)*])* $({$($config:tt)*})? $((. $($fuel:tt)*))?
+		]
+		$(@__dmutil_std)? unimplemented ! $($rest:tt)*
+	)=>{
+		$crate::eager_internal!{
+			@check_expansion[
+				[[] $modefix [$($prefix)* unimplemented !][]]
+				$([$($rest_decoded)*])* $({$($config)*})? $(($($fuel)*))?
+			]
+			$($rest)*
+		}
+	};
+	(	// 'unreachable!' is a standard library macro, so it can't be called eagerly.
+		// Push it to the prefix, such that its input is checked like any other block
+		@check_expansion[
+			[[] $modefix:tt [$($prefix:tt)*][]]
+			$([$($rest_decoded:tt)*])* $({$($config:tt)*})? $((. $($fuel:tt)*))?
+		]
+		$(@__dmutil_std)? unreachable ! $($rest:tt)*
+	)=>{
+		$crate::eager_internal!{
+			@check_expansion[
+				[[] $modefix [$($prefix)* unreachable !][]]
+				$([$($rest_decoded)*])* $({$($config)*})? $(($($fuel)*))?
+			]
+			$($rest)*
+		}
+	};
+	(	// 'vec!' is a standard library macro, so it can't be called eagerly.
+		// Push it to the prefix, such that its input is checked like any other block
+		@check_expansion[
+			[[] $modefix:tt [$($prefix:tt)*][]]
+			$([$($rest_decoded:tt)*])* $({$($config:tt)*})? $((. $($fuel:tt)*))?
+		]
+		$(@__dmutil_std)? vec ! $($rest:tt)*
+	)=>{
+		$crate::eager_internal!{
+			@check_expansion[
+				[[] $modefix [$($prefix)* vec !][]]
+				$([$($rest_decoded)*])* $({$($config)*})? $(($($fuel)*))?
+			]
+			$($rest)*
+		}
+	};
+	(	// 'write!' is a standard library macro, so it can't be called eagerly.
+		// Push it to the prefix, such that its input is checked like any other block
+		@check_expansion[
+			[[] $modefix:tt [$($prefix:tt)*][]]
+			$([$($rest_decoded:tt)*])* $({$($config:tt)*})? $((. $($fuel:tt)*))?
+		]
+		$(@__dmutil_std)? write ! $($rest:tt)*
+	)=>{
+		$crate::eager_internal!{
+			@check_expansion[
+				[[] $modefix [$($prefix)* write !][]]
+				$([$($rest_decoded)*])* $({$($config)*})? $(($($fuel)*))?
+			]
+			$($rest)*
+		}
+	};
+	(	// 'writeln!' is a standard library macro, so it can't be called eagerly.
+		// Push it to the prefix, such that its input is checked like any other block
+		@check_expansion[
+			[[] $modefix:tt [$($prefix:tt)*][]]
+			$([$($rest_decoded:tt)*])* $({$($config:tt)*})? $((. $($fuel:tt)*))?
+		]
+		$(@__dmutil_std)? writeln ! $($rest:tt)*
+	)=>{
+		$crate::eager_internal!{
+			@check_expansion[
+				[[] $modefix [$($prefix)* writeln !][]]
+				$([$($rest_decoded)*])* $({$($config)*})? $(($($fuel)*))?
+			]
+			$($rest)*
+		}
+	};
+	(	// Any other macro in the standard library is also pushed to the prefix,
+		// such that its input is checked like any other block
+		@check_expansion[
+			[[] $modefix:tt [$($prefix:tt)*][]]
+			$([$($rest_decoded:tt)*])* $({$($config:tt)*})? $((. $($fuel:tt)*))?
+		]
+		@__dmutil_std $name:ident ! $($rest:tt)*
+	)=>{
+		$crate::eager_internal!{
+			@check_expansion[
+				[[] $modefix [$($prefix)* $name !][]]
+				$([$($rest_decoded)*])* $({$($config)*})? $(($($fuel)*))?
+			]
+			$($rest)*
+		}
+	};
+	(	// If the path wasn't to a macro, remove the mark
+		@check_expansion[
+			$($decoded:tt)*
+		]
+		@__dmutil_std $($rest:tt)*
+	)=>{
+		$crate::eager_internal!{
+			@check_expansion[
+				$($decoded)*
+			]
+			$($rest)*
+		}
+	};
+// end standard library macros
+// Filter macro calls
+	(	// If the next tokens are a macro call, we are in eager mode and only some macros
+		// may be expanded, find out whether this one may (brace type)
+		@check_expansion[
+			[[] $modefix:tt [$($prefix:tt)*][]]
+			$([$($level:tt)*])*
+			{$(@depth $depth:tt)? @filter[$kind:ident $list:tt] $(@into $into:tt)? $(@trace $trace:tt)?}
+			$((. . . . . . . . . . $($fuel:tt)*))?
+		]
+		$first:ident $(:: $segment:ident)* ! {$($body:tt)*} $($rest:tt)*
+	)=>{
+		$crate::eager_internal!{
+			@filter_ask[
+				$kind $list [$first $(:: $segment)*] {$($body)*} [$first $($segment)*]
+			]
+			[
+				[[] $modefix [$($prefix)*][]]
+				$([$($level)*])*
+				{$(@depth $depth)? @filter[$kind $list] $(@into $into)? $(@trace $trace)?}
+				$(($($fuel)*))?
+			]
+			$($rest)*
+		}
+	};
+	(	// If the next tokens are a macro call, we are in eager mode and only some macros
+		// may be expanded, find out whether this one may (parenthesis type)
+		@check_expansion[
+			[[] $modefix:tt [$($prefix:tt)*][]]
+			$([$($level:tt)*])*
+			{$(@depth $depth:tt)? @filter[$kind:ident $list:tt] $(@into $into:tt)? $(@trace $trace:tt)?}
+			$((. . . . . . . . . . $($fuel:tt)*))?
+		]
+		$first:ident $(:: $segment:ident)* ! ($($body:tt)*) $($rest:tt)*
+	)=>{
+		$crate::eager_internal!{
+			@filter_ask[
+				$kind $list [$first $(:: $segment)*] ($($body)*) [$first $($segment)*]
+			]
+			[
+				[[] $modefix [$($prefix)*][]]
+				$([$($level)*])*
+				{$(@depth $depth)? @filter[$kind $list] $(@into $into)? $(@trace $trace)?}
+				$(($($fuel)*))?
+			]
+			$($rest)*
+		}
+	};
+	(	// If the next tokens are a macro call, we are in eager mode and only some macros
+		// may be expanded, find out whether this one may (bracket type)
+		@check_expansion[
+			[[] $modefix:tt [$($prefix:tt)*][]]
+			$([$($level:tt)*])*
+			{$(@depth $depth:tt)? @filter[$kind:ident $list:tt] $(@into $into:tt)? $(@trace $trace:tt)?}
+			$((. . . . . . . . . . $($fuel:tt)*))?
+		]
+		$first:ident $(:: $segment:ident)* ! [$($body:tt)*] $($rest:tt)*
+	)=>{
+		$crate::eager_internal!{
+			@filter_ask[
+				$kind $list [$first $(:: $segment)*] [$($body)*] [$first $($segment)*]
+			]
+			[
+				[[] $modefix [$($prefix)*][]]
+				$([$($level)*])*
+				{$(@depth $depth)? @filter[$kind $list] $(@into $into)? $(@trace $trace)?}
+				$(($($fuel)*))?
+			]
+			$($rest)*
+		}
+	};
+	(	// If the next tokens are a macro call, we are in eager mode and only some macros
+		// may be expanded, find out whether this one may (leading '::', brace type)
+		@check_expansion[
+			[[] $modefix:tt [$($prefix:tt)*][]]
+			$([$($level:tt)*])*
+			{$(@depth $depth:tt)? @filter[$kind:ident $list:tt] $(@into $into:tt)? $(@trace $trace:tt)?}
+			$((. . . . . . . . . . $($fuel:tt)*))?
+		]
+		:: $first:ident $(:: $segment:ident)* ! {$($body:tt)*} $($rest:tt)*
+	)=>{
+		$crate::eager_internal!{
+			@filter_ask[
+				$kind $list [:: $first $(:: $segment)*] {$($body)*} [$first $($segment)*]
+			]
+			[
+				[[] $modefix [$($prefix)*][]]
+				$([$($level)*])*
+				{$(@depth $depth)? @filter[$kind $list] $(@into $into)? $(@trace $trace)?}
+				$(($($fuel)*))?
+			]
+			$($rest)*
+		}
+	};
+	(	// If the next tokens are a macro call, we are in eager mode and only some macros
+		// may be expanded, find out whether this one may (leading '::', parenthesis type)
+		@check_expansion[
+			[[] $modefix:tt [$($prefix:tt)*][]]
+			$([$($level:tt)*])*
+			{$(@depth $depth:tt)? @filter[$kind:ident $list:tt] $(@into $into:tt)? $(@trace $trace:tt)?}
+			$((. . . . . . . . . . $($fuel:tt)*))?
+		]
+		:: $first:ident $(:: $segment:ident)* ! ($($body:tt)*) $($rest:tt)*
+	)=>{
+		$crate::eager_internal!{
+			@filter_ask[
+				$kind $list [:: $first $(:: $segment)*] ($($body)*) [$first $($segment)*]
+			]
+			[
+				[[] $modefix [$($prefix)*][]]
+				$([$($level)*])*
+				{$(@depth $depth)? @filter[$kind $list] $(@into $into)? $(@trace $trace)?}
+				$(($($fuel)*))?
+			]
+			$($rest)*
+		}
+	};
+	(	// If the next tokens are a macro call, we are in eager mode and only some macros
+		// may be expanded, find out whether this one may (leading '::', bracket type)
+		@check_expansion[
+			[[] $modefix:tt [$($prefix:tt)*][]]
+			$([$($level:tt)*])*
+			{$(@depth $depth:tt)? @filter[$kind:ident $list:tt] $(@into $into:tt)? $(@trace $trace:tt)?}
+			$((. . . . . . . . . . $($fuel:tt)*))?
+		]
+		:: $first:ident $(:: $segment:ident)* ! [$($body:tt)*] $($rest:tt)*
+	)=>{
+		$crate::eager_internal!{
+			@filter_ask[
+				$kind $list [:: $first $(:: $segment)*] [$($body)*] [$first $($segment)*]
+			]
+			[
+				[[] $modefix [$($prefix)*][]]
+				$([$($level)*])*
+				{$(@depth $depth)? @filter[$kind $list] $(@into $into)? $(@trace $trace)?}
+				$(($($fuel)*))?
+			]
+			$($rest)*
+		}
+	};
+	(	// If there is too little fuel left to find out whether the macro may be expanded
+		// and to expand it, stop with an error
+		@check_expansion[
+			[[] $modefix:tt $prefix:tt []]
+			$([$($level:tt)*])*
+			{$(@depth $depth:tt)? @filter $filter:tt $(@into $into:tt)? $(@trace $trace:tt)?}
+			($($fuel:tt)*)
+		]
+		$(::)? $first:ident $(:: $segment:ident)* ! $($rest:tt)*
+	)=>{
+		compile_error!{
+			concat!(
+				"eager!: ran out of fuel before expanding '", stringify!($first $(:: $segment)*!),
+				"'. Remaining input: '", stringify!($first $(:: $segment)*! $($rest)*), "'"
+			)
+		}
+	};
+	(	// Only the name of the called macro is compared, so remove the rest of the path
+		@filter_ask[
+			only $list:tt $path:tt $args:tt [$skip:ident $($names:ident)+]
+		]
+		$($rest:tt)*
+	)=>{
+		$crate::eager_internal!{
+			@filter_ask[only $list $path $args [$($names)+]]
+			$($rest)*
+		}
+	};
+	(	// Ask the next listed macro whether it is the one being called, using the fuel
+		// for asking if given. If it is, the call may be expanded
+		@filter_ask[
+			only [[$($entry:tt)+] $($list:tt)*] $path:tt $args:tt [$name:ident]
+		]
+		[$([$($level:tt)*])* {$($config:tt)*} $((. . . $($fuel:tt)*))?] $($rest:tt)*
+	)=>{
+		$($entry)+!{
+			@__dmutil_is[@__dmutil_version[1] [$crate::eager_internal] $name]
+			[@filter_eager[$path $args] [$([$($level)*])* {$($config)*} $(($($fuel)*))?] $($rest)*]
+			[
+				@filter_ask[only [$($list)*] $path $args [$name]]
+				[$([$($level)*])* {$($config)*} $(($($fuel)*))?] $($rest)*
+			]
+		}
+	};
+	(	// If there is too little fuel left to ask, stop with an error
+		@filter_ask[only [$entry:tt $($list:tt)*] [$($path:tt)+] $args:tt $names:tt]
+		$stack:tt $($rest:tt)*
+	)=>{
+		compile_error!{
+			concat!(
+				"eager!: ran out of fuel before expanding '", stringify!($($path)+!),
+				"'. Remaining input: '", stringify!($($path)+! $args $($rest)*), "'"
+			)
+		}
+	};
+	(	// A listed macro replies with the state it was given, so check its version
+		// before continuing with the reply
+		@from_is[@__dmutil_version[1] $return:tt]
+		$($reply:tt)*
+	)=>{
+		$crate::eager_internal!{
+			$($reply)*
+		}
+	};
+	(
+		@from_is[@__dmutil_version[$($version:tt)*] $return:tt]
+		$($reply:tt)*
+	)=>{
+		$crate::eager_internal!{@version_mismatch[$($version)*]}
+	};
+	(
+		@from_is[$($state:tt)*]
+		$($reply:tt)*
+	)=>{
+		$crate::eager_internal!{@version_mismatch[]}
+	};
+	(	// If none of the listed macros are being called, the call is lazy
+		@filter_ask[only [] $path:tt $args:tt $names:tt]
+		$($rest:tt)*
+	)=>{
+		$crate::eager_internal!{
+			@filter_lazy[$path $args]
+			$($rest)*
+		}
+	};
+	(	// Ask the called macro whether it is the next listed macro, using the fuel for asking
+		// if given, such that the listed macros need not be 'eager!'-enabled.
+		// If it is, the call is lazy
+		@filter_ask[
+			except [[$name:ident] $($list:tt)*] [$($path:tt)+] $args:tt $names:tt
+		]
+		[$([$($level:tt)*])* {$($config:tt)*} $((. . . $($fuel:tt)*))?] $($rest:tt)*
+	)=>{
+		$($path)+!{
+			@__dmutil_is[@__dmutil_version[1] [$crate::eager_internal] $name]
+			[
+				@filter_lazy[[$($path)+] $args]
+				[$([$($level)*])* {$($config)*} $(($($fuel)*))?] $($rest)*
+			]
+			[
+				@filter_ask[except [$($list)*] [$($path)+] $args $names]
+				[$([$($level)*])* {$($config)*} $(($($fuel)*))?] $($rest)*
+			]
+		}
+	};
+	(	// If there is too little fuel left to ask, stop with an error
+		@filter_ask[except [$entry:tt $($list:tt)*] [$($path:tt)+] $args:tt $names:tt]
+		$stack:tt $($rest:tt)*
+	)=>{
+		compile_error!{
+			concat!(
+				"eager!: ran out of fuel before expanding '", stringify!($($path)+!),
+				"'. Remaining input: '", stringify!($($path)+! $args $($rest)*), "'"
+			)
+		}
+	};
+	(	// If none of the listed macros are being called, the call may be expanded
+		@filter_ask[except [] $path:tt $args:tt $names:tt]
+		$($rest:tt)*
+	)=>{
+		$crate::eager_internal!{
+			@filter_eager[$path $args]
+			$($rest)*
+		}
+	};
+	(	// If the call may be expanded, give the macro the block before it is checked,
+		// remembering the path to the macro (brace type)
+		@filter_eager[[$($path:tt)+] {$($body:tt)*}]
+		[
+			[[] $modefix:tt [$($prefix:tt)*][]]
+			$($rest_decoded:tt)*
+		]
+		$($rest:tt)*
+	)=>{
+		$($path)+!{
+			@__dmutil_eager_args[
+				@__dmutil_version[1] [$crate::eager_internal]
+				[[] $modefix [$($prefix)*][$($rest)*]{}[$($path)+]]
+				$($rest_decoded)*
+			]
+			$($body)*
+		}
+	};
+	(	// If the call may be expanded, give the macro the block before it is checked,
+		// remembering the path to the macro (parenthesis type)
+		@filter_eager[[$($path:tt)+] ($($body:tt)*)]
+		[
+			[[] $modefix:tt [$($prefix:tt)*][]]
+			$($rest_decoded:tt)*
+		]
+		$($rest:tt)*
+	)=>{
+		$($path)+!{
+			@__dmutil_eager_args[
+				@__dmutil_version[1] [$crate::eager_internal]
+				[[] $modefix [$($prefix)*][$($rest)*]()[$($path)+]]
+				$($rest_decoded)*
+			]
+			$($body)*
+		}
+	};
+	(	// If the call may be expanded, give the macro the block before it is checked,
+		// remembering the path to the macro (bracket type)
+		@filter_eager[[$($path:tt)+] [$($body:tt)*]]
+		[
+			[[] $modefix:tt [$($prefix:tt)*][]]
+			$($rest_decoded:tt)*
+		]
+		$($rest:tt)*
+	)=>{
+		$($path)+!{
+			@__dmutil_eager_args[
+				@__dmutil_version[1] [$crate::eager_internal]
+				[[] $modefix [$($prefix)*][$($rest)*][][$($path)+]]
+				$($rest_decoded)*
+			]
+			$($body)*
+		}
+	};
+	(	// If the call is lazy, move the path to the prefix and check the block in lazy mode,
+		// as if it was in a 'lazy!' block (brace type)
+		@filter_lazy[[$($path:tt)*] {$($body:tt)*}]
+		[
+			[[] $modefix:tt [$($prefix:tt)*][]]
+			$($rest_decoded:tt)*
+		]
+		$($rest:tt)*
+	)=>{
+		$crate::eager_internal!{
+			@check_expansion[
+				[[@lazy][][][]]
+				[[] $modefix [$($prefix)* $($path)* !][$($rest)*]{}]
+				$($rest_decoded)*
+			]
+			$($body)*
+		}
+	};
+	(	// If the call is lazy, move the path to the prefix and check the block in lazy mode,
+		// as if it was in a 'lazy!' block (parenthesis type)
+		@filter_lazy[[$($path:tt)*] ($($body:tt)*)]
+		[
+			[[] $modefix:tt [$($prefix:tt)*][]]
+			$($rest_decoded:tt)*
+		]
+		$($rest:tt)*
+	)=>{
+		$crate::eager_internal!{
+			@check_expansion[
+				[[@lazy][][][]]
+				[[] $modefix [$($prefix)* $($path)* !][$($rest)*]()]
+				$($rest_decoded)*
+			]
+			$($body)*
+		}
+	};
+	(	// If the call is lazy, move the path to the prefix and check the block in lazy mode,
+		// as if it was in a 'lazy!' block (bracket type)
+		@filter_lazy[[$($path:tt)*] [$($body:tt)*]]
+		[
+			[[] $modefix:tt [$($prefix:tt)*][]]
+			$($rest_decoded:tt)*
+		]
+		$($rest:tt)*
+	)=>{
+		$crate::eager_internal!{
+			@check_expansion[
+				[[@lazy][][][]]
+				[[] $modefix [$($prefix)* $($path)* !][$($rest)*][]]
+				$($rest_decoded)*
+			]
+			$($body)*
+		}
+	};
+// end filter macro calls
+// Decode macro calls
+	(	// If the next tokens are a macro call and we are in eager mode, give the macro
+		// the block before it is checked, remembering the path to the macro (brace type)
+		@check_expansion[
+			[[] $modefix:tt [$($prefix:tt)*][]]
+			$([$($rest_decoded:tt)*])* $({$($config:tt)*})? $((. . . . . . $($fuel:tt)*))?
+		]
+		$first:ident $(:: $segment:ident)* ! {$($body:tt)*} $($rest:tt)*
+	)=>{
+		$first $(:: $segment)*!{
+			@__dmutil_eager_args[
+				@__dmutil_version[1] [$crate::eager_internal]
+				[[] $modefix [$($prefix)*][$($rest)*]{}[$first $(:: $segment)*]]
+				$([$($rest_decoded)*])* $({$($config)*})? $(($($fuel)*))?
+			]
+			$($body)*
+		}
+	};
+	(	// If the next tokens are a macro call and we are in eager mode, give the macro
+		// the block before it is checked, remembering the path to the macro (parenthesis type)
+		@check_expansion[
+			[[] $modefix:tt [$($prefix:tt)*][]]
+			$([$($rest_decoded:tt)*])* $({$($config:tt)*})? $((. . . . . . $($fuel:tt)*))?
+		]
+		$first:ident $(:: $segment:ident)* ! ($($body:tt)*) $($rest:tt)*
+	)=>{
+		$first $(:: $segment)*!{
+			@__dmutil_eager_args[
+				@__dmutil_version[1] [$crate::eager_internal]
+				[[] $modefix [$($prefix)*][$($rest)*]()[$first $(:: $segment)*]]
+				$([$($rest_decoded)*])* $({$($config)*})? $(($($fuel)*))?
+			]
+			$($body)*
+		}
+	};
+	(	// If the next tokens are a macro call and we are in eager mode, give the macro
+		// the block before it is checked, remembering the path to the macro (bracket type)
+		@check_expansion[
+			[[] $modefix:tt [$($prefix:tt)*][]]
+			$([$($rest_decoded:tt)*])* $({$($config:tt)*})? $((. . . . . . $($fuel:tt)*))?
+		]
+		$first:ident $(:: $segment:ident)* ! [$($body:tt)*] $($rest:tt)*
+	)=>{
+		$first $(:: $segment)*!{
+			@__dmutil_eager_args[
+				@__dmutil_version[1] [$crate::eager_internal]
+				[[] $modefix [$($prefix)*][$($rest)*][][$first $(:: $segment)*]]
+				$([$($rest_decoded)*])* $({$($config)*})? $(($($fuel)*))?
+			]
+			$($body)*
+		}
+	};
+	(	// If the next tokens are a macro call and we are in eager mode, give the macro
+		// the block before it is checked, remembering the path to the macro (leading '::', brace type)
+		@check_expansion[
+			[[] $modefix:tt [$($prefix:tt)*][]]
+			$([$($rest_decoded:tt)*])* $({$($config:tt)*})? $((. . . . . . $($fuel:tt)*))?
+		]
+		:: $first:ident $(:: $segment:ident)* ! {$($body:tt)*} $($rest:tt)*
+	)=>{
+		:: $first $(:: $segment)*!{
+			@__dmutil_eager_args[
+				@__dmutil_version[1] [$crate::eager_internal]
+				[[] $modefix [$($prefix)*][$($rest)*]{}[:: $first $(:: $segment)*]]
+				$([$($rest_decoded)*])* $({$($config)*})? $(($($fuel)*))?
+			]
+			$($body)*
+		}
+	};
+	(	// If the next tokens are a macro call and we are in eager mode, give the macro
+		// the block before it is checked, remembering the path to the macro (leading '::', parenthesis type)
+		@check_expansion[
+			[[] $modefix:tt [$($prefix:tt)*][]]
+			$([$($rest_decoded:tt)*])* $({$($config:tt)*})? $((. . . . . . $($fuel:tt)*))?
+		]
+		:: $first:ident $(:: $segment:ident)* ! ($($body:tt)*) $($rest:tt)*
+	)=>{
+		:: $first $(:: $segment)*!{
+			@__dmutil_eager_args[
+				@__dmutil_version[1] [$crate::eager_internal]
+				[[] $modefix [$($prefix)*][$($rest)*]()[:: $first $(:: $segment)*]]
+				$([$($rest_decoded)*])* $({$($config)*})? $(($($fuel)*))?
+			]
+			$($body)*
+		}
+	};
+	(	// If the next tokens are a macro call and we are in eager mode, give the macro
+		// the block before it is checked, remembering the path to the macro (leading '::', bracket type)
+		@check_expansion[
+			[[] $modefix:tt [$($prefix:tt)*][]]
+			$([$($rest_decoded:tt)*])* $({$($config:tt)*})? $((. . . . . . $($fuel:tt)*))?
+		]
+		:: $first:ident $(:: $segment:ident)* ! [$($body:tt)*] $($rest:tt)*
+	)=>{
+		:: $first $(:: $segment)*!{
+			@__dmutil_eager_args[
+				@__dmutil_version[1] [$crate::eager_internal]
+				[[] $modefix [$($prefix)*][$($rest)*][][:: $first $(:: $segment)*]]
+				$([$($rest_decoded)*])* $({$($config)*})? $(($($fuel)*))?
+			]
+			$($body)*
+		}
+	};
+	(	// If there is too little fuel left to call the macro and return from it,
+		// stop with an error
+		@check_expansion[
+			[[] $modefix:tt $prefix:tt []]
+			$([$($level:tt)*])*
+			$({$($config:tt)*})?
+			($($fuel:tt)*)
+		]
+		$(::)? $first:ident $(:: $segment:ident)* ! $($rest:tt)*
+	)=>{
+		compile_error!{
+			concat!(
+				"eager!: ran out of fuel before expanding '", stringify!($first $(:: $segment)*!),
+				"'. Remaining input: '", stringify!($first $(:: $segment)*! $($rest)*), "'"
+			)
+		}
+	};
+// end decode macro calls
+// Simple tokens
+	(	// If the second token is a block, the first token is simple,
+		// so add it to the prefix (brace type)
+		@check_expansion[
+			[$lazy:tt $modefix:tt [$($prefix:tt)*][]]
+			$([$($rest_decoded:tt)*])* $({$($config:tt)*})? $((. $($fuel:tt)*))?
+		]
+		$t1:tt {$($body:tt)*} $($rest:tt)*
+	)=>{
+		$crate::eager_internal!{
+			@check_expansion[
+				[$lazy $modefix[$($prefix)* $t1][]]
+				$([$($rest_decoded)*])* $({$($config)*})? $(($($fuel)*))?
+			]
+			{$($body)*} $($rest)*
+		}
+	};
+	(	// If the second token is a block, the first token is simple,
+		// so add it to the prefix (parenthesis type)
+		@check_expansion[
+			[$lazy:tt $modefix:tt [$($prefix:tt)*][]]
+			$([$($rest_decoded:tt)*])* $({$($config:tt)*})? $((. $($fuel:tt)*))?
+		]
+		$t1:tt ($($body:tt)*) $($rest:tt)*
+	)=>{
+		$crate::eager_internal!{
+			@check_expansion[
+				[$lazy $modefix[$($prefix)* $t1][]]
+				$([$($rest_decoded)*])* $({$($config)*})? $(($($fuel)*))?
+			]
+			($($body)*) $($rest)*
+		}
+	};
+	(	// If the second token is a block, the first token is simple,
+		// so add it to the prefix (bracket type)
+		@check_expansion[
+			[$lazy:tt $modefix:tt [$($prefix:tt)*][]]
+			$([$($rest_decoded:tt)*])* $({$($config:tt)*})? $((. $($fuel:tt)*))?
+		]
+		$t1:tt [$($body:tt)*] $($rest:tt)*
+	)=>{
+		$crate::eager_internal!{
+			@check_expansion[
+				[$lazy $modefix[$($prefix)* $t1][]]
+				$([$($rest_decoded)*])* $({$($config)*})? $(($($fuel)*))?
+			]
+			[$($body)*] $($rest)*
+		}
+	};
+	(	// If the second token is '!', the first token is still simple,
+		// since it didn't start a macro call
+		@check_expansion[
+			[$lazy:tt $modefix:tt [$($prefix:tt)*][]]
+			$([$($rest_decoded:tt)*])* $({$($config:tt)*})? $((. $($fuel:tt)*))?
+		]
+		$t1:tt ! $($rest:tt)*
+	)=>{
+		$crate::eager_internal!{
+			@check_expansion[
+				[$lazy $modefix[$($prefix)* $t1][]]
+				$([$($rest_decoded)*])* $({$($config)*})? $(($($fuel)*))?
+			]
+			! $($rest)*
+		}
+	};
+	(	// If the second token is '::', the first token is still simple,
+		// since it didn't start a macro call
+		@check_expansion[
+			[$lazy:tt $modefix:tt [$($prefix:tt)*][]]
+			$([$($rest_decoded:tt)*])* $({$($config:tt)*})? $((. $($fuel:tt)*))?
+		]
+		$t1:tt :: $($rest:tt)*
+	)=>{
+		$crate::eager_internal!{
+			@check_expansion[
+				[$lazy $modefix[$($prefix)* $t1][]]
+				$([$($rest_decoded)*])* $({$($config)*})? $(($($fuel)*))?
+			]
+			:: $($rest)*
+		}
+	};
+	(	// If the second token is '@', the first token is simple,
+		// so add it to the prefix
+		@check_expansion[
+			[$lazy:tt $modefix:tt [$($prefix:tt)*][]]
+			$([$($rest_decoded:tt)*])* $({$($config:tt)*})? $((. $($fuel:tt)*))?
+		]
+		$t1:tt @ $($rest:tt)*
+	)=>{
+		$crate::eager_internal!{
+			@check_expansion[
+				[$lazy $modefix[$($prefix)* $t1][]]
+				$([$($rest_decoded)*])* $({$($config)*})? $(($($fuel)*))?
+			]
+			@ $($rest)*
+		}
+	};
+	(	// If the third token is a block, the tokens before it are simple,
+		// so add them to the prefix all at once (brace type)
+		@check_expansion[
+			[$lazy:tt $modefix:tt [$($prefix:tt)*][]]
+			$([$($rest_decoded:tt)*])* $({$($config:tt)*})? $((. $($fuel:tt)*))?
+		]
+		$t1:tt $t2:tt {$($body:tt)*} $($rest:tt)*
+	)=>{
+		$crate::eager_internal!{
+			@check_expansion[
+				[$lazy $modefix[$($prefix)* $t1 $t2][]]
+				$([$($rest_decoded)*])* $({$($config)*})? $(($($fuel)*))?
+			]
+			{$($body)*} $($rest)*
+		}
+	};
+	(	// If the third token is a block, the tokens before it are simple,
+		// so add them to the prefix all at once (parenthesis type)
+		@check_expansion[
+			[$lazy:tt $modefix:tt [$($prefix:tt)*][]]
+			$([$($rest_decoded:tt)*])* $({$($config:tt)*})? $((. $($fuel:tt)*))?
+		]
+		$t1:tt $t2:tt ($($body:tt)*) $($rest:tt)*
+	)=>{
+		$crate::eager_internal!{
+			@check_expansion[
+				[$lazy $modefix[$($prefix)* $t1 $t2][]]
+				$([$($rest_decoded)*])* $({$($config)*})? $(($($fuel)*))?
+			]
+			($($body)*) $($rest)*
+		}
+	};
+	(	// If the third token is a block, the tokens before it are simple,
+		// so add them to the prefix all at once (bracket type)
+		@check_expansion[
+			[$lazy:tt $modefix:tt [$($prefix:tt)*][]]
+			$([$($rest_decoded:tt)*])* $({$($config:tt)*})? $((. $($fuel:tt)*))?
+		]
+		$t1:tt $t2:tt [$($body:tt)*] $($rest:tt)*
+	)=>{
+		$crate::eager_internal!{
+			@check_expansion[
+				[$lazy $modefix[$($prefix)* $t1 $t2][]]
+				$([$($rest_decoded)*])* $({$($config)*})? $(($($fuel)*))?
+			]
+			[$($body)*] $($rest)*
+		}
+	};
+	(	// If the third token is '!', the token before it may start a path,
+		// but the ones before that are simple, so add them to the prefix all at once
+		@check_expansion[
+			[$lazy:tt $modefix:tt [$($prefix:tt)*][]]
+			$([$($rest_decoded:tt)*])* $({$($config:tt)*})? $((. $($fuel:tt)*))?
+		]
+		$t1:tt $t2:tt ! $($rest:tt)*
+	)=>{
+		$crate::eager_internal!{
+			@check_expansion[
+				[$lazy $modefix[$($prefix)* $t1][]]
+				$([$($rest_decoded)*])* $({$($config)*})? $(($($fuel)*))?
+			]
+			$t2 ! $($rest)*
+		}
+	};
+	(	// If the third token is '::', the token before it may start a path,
+		// but the ones before that are simple, so add them to the prefix all at once
+		@check_expansion[
+			[$lazy:tt $modefix:tt [$($prefix:tt)*][]]
+			$([$($rest_decoded:tt)*])* $({$($config:tt)*})? $((. $($fuel:tt)*))?
+		]
+		$t1:tt $t2:tt :: $($rest:tt)*
+	)=>{
+		$crate::eager_internal!{
+			@check_expansion[
+				[$lazy $modefix[$($prefix)* $t1][]]
+				$([$($rest_decoded)*])* $({$($config)*})? $(($($fuel)*))?
+			]
+			$t2 :: $($rest)*
+		}
+	};
+	(	// If the third token is '@', the tokens before it are simple,
+		// so add them to the prefix all at once
+		@check_expansion[
+			[$lazy:tt $modefix:tt [$($prefix:tt)*][]]
+			$([$($rest_decoded:tt)*])* $({$($config:tt)*})? $((. $($fuel:tt)*))?
+		]
+		$t1:tt $t2:tt @ $($rest:tt)*
+	)=>{
+		$crate::eager_internal!{
+			@check_expansion[
+				[$lazy $modefix[$($prefix)* $t1 $t2][]]
+				$([$($rest_decoded)*])* $({$($config)*})? $(($($fuel)*))?
+			]
+			@ $($rest)*
+		}
+	};
+	(	// If the fourth token is a block, the tokens before it are simple,
+		// so add them to the prefix all at once (brace type)
+		@check_expansion[
+			[$lazy:tt $modefix:tt [$($prefix:tt)*][]]
+			$([$($rest_decoded:tt)*])* $({$($config:tt)*})? $((. $($fuel:tt)*))?
+		]
+		$t1:tt $t2:tt $t3:tt {$($body:tt)*} $($rest:tt)*
+	)=>{
+		$crate::eager_internal!{
+			@check_expansion[
+				[$lazy $modefix[$($prefix)* $t1 $t2 $t3][]]
+				$([$($rest_decoded)*])* $({$($config)*})? $(($($fuel)*))?
+			]
+			{$($body)*} $($rest)*
+		}
+	};
+	(	// If the fourth token is a block, the tokens before it are simple,
+		// so add them to the prefix all at once (parenthesis type)
+		@check_expansion[
+			[$lazy:tt $modefix:tt [$($prefix:tt)*][]]
+			$([$($rest_decoded:tt)*])* $({$($config:tt)*})? $((. $($fuel:tt)*))?
+		]
+		$t1:tt $t2:tt $t3:tt ($($body:tt)*) $($rest:tt)*
+	)=>{
+		$crate::eager_internal!{
+			@check_expansion[
+				[$lazy $modefix[$($prefix)* $t1 $t2 $t3][]]
+				$([$($rest_decoded)*])* $({$($config)*})? $(($($fuel)*))?
+			]
+			($($body)*) $($rest)*
+		}
+	};
+	(	// If the fourth token is a block, the tokens before it are simple,
+		// so add them to the prefix all at once (bracket type)
+		@check_expansion[
+			[$lazy:tt $modefix:tt [$($prefix:tt)*][]]
+			$([$($rest_decoded:tt)*])* $({$($config:tt)*})? $((. $($fuel:tt)*))?
+		]
+		$t1:tt $t2:tt $t3:tt [$($body:tt)*] $($rest:tt)*
+	)=>{
+		$crate::eager_internal!{
+			@check_expansion[
+				[$lazy $modefix[$($prefix)* $t1 $t2 $t3][]]
+				$([$($rest_decoded)*])* $({$($config)*})? $(($($fuel)*))?
+			]
+			[$($body)*] $($rest)*
+		}
+	};
+	(	// If the fourth token is '!', the token before it may start a path,
+		// but the ones before that are simple, so add them to the prefix all at once
+		@check_expansion[
+			[$lazy:tt $modefix:tt [$($prefix:tt)*][]]
+			$([$($rest_decoded:tt)*])* $({$($config:tt)*})? $((. $($fuel:tt)*))?
+		]
+		$t1:tt $t2:tt $t3:tt ! $($rest:tt)*
+	)=>{
+		$crate::eager_internal!{
+			@check_expansion[
+				[$lazy $modefix[$($prefix)* $t1 $t2][]]
+				$([$($rest_decoded)*])* $({$($config)*})? $(($($fuel)*))?
+			]
+			$t3 ! $($rest)*
+		}
+	};
+	(	// If the fourth token is '::', the token before it may start a path,
+		// but the ones before that are simple, so add them to the prefix all at once
+		@check_expansion[
+			[$lazy:tt $modefix:tt [$($prefix:tt)*][]]
+			$([$($rest_decoded:tt)*])* $({$($config:tt)*})? $((. $($fuel:tt)*))?
+		]
+		$t1:tt $t2:tt $t3:tt :: $($rest:tt)*
+	)=>{
+		$crate::eager_internal!{
+			@check_expansion[
+				[$lazy $modefix[$($prefix)* $t1 $t2][]]
+				$([$($rest_decoded)*])* $({$($config)*})? $(($($fuel)*))?
+			]
+			$t3 :: $($rest)*
+		}
+	};
+	(	// If the fourth token is '@', the tokens before it are simple,
+		// so add them to the prefix all at once
+		@check_expansion[
+			[$lazy:tt $modefix:tt [$($prefix:tt)*][]]
+			$([$($rest_decoded:tt)*])* $({$($config:tt)*})? $((. $($fuel:tt)*))?
+		]
+		$t1:tt $t2:tt $t3:tt @ $($rest:tt)*
+	)=>{
+		$crate::eager_internal!{
+			@check_expansion[
+				[$lazy $modefix[$($prefix)* $t1 $t2 $t3][]]
+				$([$($rest_decoded)*])* $({$($config)*})? $(($($fuel)*))?
+			]
+			@ $($rest)*
+		}
+	};
+	(	// If the fifth token is a block, the tokens before it are simple,
+		// so add them to the prefix all at once (brace type)
+		@check_expansion[
+			[$lazy:tt $modefix:tt [$($prefix:tt)*][]]
+			$([$($rest_decoded:tt)*])* $({$($config:tt)*})? $((. $($fuel:tt)*))?
+		]
+		$t1:tt $t2:tt $t3:tt $t4:tt {$($body:tt)*} $($rest:tt)*
+	)=>{
+		$crate::eager_internal!{
+			@check_expansion[
+				[$lazy $modefix[$($prefix)* $t1 $t2 $t3 $t4][]]
+				$([$($rest_decoded)*])* $({$($config)*})? $(($($fuel)*))?
+			]
+			{$($body)*} $($rest)*
+		}
+	};
+	(	// If the fifth token is a block, the tokens before it are simple,
+		// so add them to the prefix all at once (parenthesis type)
+		@check_expansion[
+			[$lazy:tt $modefix:tt [$($prefix:tt)*][]]
+			$([$($rest_decoded:tt)*])* $({$($config:tt)*})? $((. $($fuel:tt)*))?
+		]
+		$t1:tt $t2:tt $t3:tt $t4:tt ($($body:tt)*) $($rest:tt)*
+	)=>{
+		$crate::eager_internal!{
+			@check_expansion[
+				[$lazy $modefix[$($prefix)* $t1 $t2 $t3 $t4][]]
+				$([$($rest_decoded)*])* $({$($config)*})? $(($($fuel)*))?
+			]
+			($($body)*) $($rest)*
+		}
+	};
+	(	// If the fifth token is a block, the tokens before it are simple,
+		// so add them to the prefix all at once (bracket type)
+		@check_expansion[
+			[$lazy:tt $modefix:tt [$($prefix:tt)*][]]
+			$([$($rest_decoded:tt)*])* $({$($config:tt)*})? $((. $($fuel:tt)*))?
+		]
+		$t1:tt $t2:tt $t3:tt $t4:tt [$($body:tt)*] $($rest:tt)*
+	)=>{
+		$crate::eager_internal!{
+			@check_expansion[
+				[$lazy $modefix[$($prefix)* $t1 $t2 $t3 $t4][]]
+				$([$($rest_decoded)*])* $({$($config)*})? $(($($fuel)*))?
+			]
+			[$($body)*] $($rest)*
+		}
+	};
+	(	// If the fifth token is '!', the token before it may start a path,
+		// but the ones before that are simple, so add them to the prefix all at once
+		@check_expansion[
+			[$lazy:tt $modefix:tt [$($prefix:tt)*][]]
+			$([$($rest_decoded:tt)*])* $({$($config:tt)*})? $((. $($fuel:tt)*))?
+		]
+		$t1:tt $t2:tt $t3:tt $t4:tt ! $($rest:tt)*
+	)=>{
+		$crate::eager_internal!{
+			@check_expansion[
+				[$lazy $modefix[$($prefix)* $t1 $t2 $t3][]]
+				$([$($rest_decoded)*])* $({$($config)*})? $(($($fuel)*))?
+			]
+			$t4 ! $($rest)*
+		}
+	};
+	(	// If the fifth token is '::', the token before it may start a path,
+		// but the ones before that are simple, so add them to the prefix all at once
+		@check_expansion[
+			[$lazy:tt $modefix:tt [$($prefix:tt)*][]]
+			$([$($rest_decoded:tt)*])* $({$($config:tt)*})? $((. $($fuel:tt)*))?
+		]
+		$t1:tt $t2:tt $t3:tt $t4:tt :: $($rest:tt)*
+	)=>{
+		$crate::eager_internal!{
+			@check_expansion[
+				[$lazy $modefix[$($prefix)* $t1 $t2 $t3][]]
+				$([$($rest_decoded)*])* $({$($config)*})? $(($($fuel)*))?
+			]
+			$t4 :: $($rest)*
+		}
+	};
+	(	// If the fifth token is '@', the tokens before it are simple,
+		// so add them to the prefix all at once
+		@check_expansion[
+			[$lazy:tt $modefix:tt [$($prefix:tt)*][]]
+			$([$($rest_decoded:tt)*])* $({$($config:tt)*})? $((. $($fuel:tt)*))?
+		]
+		$t1:tt $t2:tt $t3:tt $t4:tt @ $($rest:tt)*
+	)=>{
+		$crate::eager_internal!{
+			@check_expansion[
+				[$lazy $modefix[$($prefix)* $t1 $t2 $t3 $t4][]]
+				$([$($rest_decoded)*])* $({$($config)*})? $(($($fuel)*))?
+			]
+			@ $($rest)*
+		}
+	};
+	(	// If the sixth token is a block, the tokens before it are simple,
+		// so add them to the prefix all at once (brace type)
+		@check_expansion[
+			[$lazy:tt $modefix:tt [$($prefix:tt)*][]]
+			$([$($rest_decoded:tt)*])* $({$($config:tt)*})? $((. $($fuel:tt)*))?
+		]
+		$t1:tt $t2:tt $t3:tt $t4:tt $t5:tt {$($body:tt)*} $($rest:tt)*
+	)=>{
+		$crate::eager_internal!{
+			@check_expansion[
+				[$lazy $modefix[$($prefix)* $t1 $t2 $t3 $t4 $t5][]]
+				$([$($rest_decoded)*])* $({$($config)*})? $(($($fuel)*))?
+			]
+			{$($body)*} $($rest)*
+		}
+	};
+	(	// If the sixth token is a block, the tokens before it are simple,
+		// so add them to the prefix all at once (parenthesis type)
+		@check_expansion[
+			[$lazy:tt $modefix:tt [$($prefix:tt)*][]]
+			$([$($rest_decoded:tt)*])* $({$($config:tt)*})? $((. $($fuel:tt)*))?
+		]
+		$t1:tt $t2:tt $t3:tt $t4:tt $t5:tt ($($body:tt)*) $($rest:tt)*
+	)=>{
+		$crate::eager_internal!{
+			@check_expansion[
+				[$lazy $modefix[$($prefix)* $t1 $t2 $t3 $t4 $t5][]]
+				$([$($rest_decoded)*])* $({$($config)*})? $(($($fuel)*))?
+			]
+			($($body)*) $($rest)*
+		}
+	};
+	(	// If the sixth token is a block, the tokens before it are simple,
+		// so add them to the prefix all at once (bracket type)
+		@check_expansion[
+			[$lazy:tt $modefix:tt [$($prefix:tt)*][]]
+			$([$($rest_decoded:tt)*])* $({$($config:tt)*})? $((. $($fuel:tt)*))?
+		]
+		$t1:tt $t2:tt $t3:tt $t4:tt $t5:tt [$($body:tt)*] $($rest:tt)*
+	)=>{
+		$crate::eager_internal!{
+			@check_expansion[
+				[$lazy $modefix[$($prefix)* $t1 $t2 $t3 $t4 $t5][]]
+				$([$($rest_decoded)*])* $({$($config)*})? $(($($fuel)*))?
+			]
+			[$($body)*] $($rest)*
+		}
+	};
+	(	// If the sixth token is '!', the token before it may start a path,
+		// but the ones before that are simple, so add them to the prefix all at once
+		@check_expansion[
+			[$lazy:tt $modefix:tt [$($prefix:tt)*][]]
+			$([$($rest_decoded:tt)*])* $({$($config:tt)*})? $((. $($fuel:tt)*))?
+		]
+		$t1:tt $t2:tt $t3:tt $t4:tt $t5:tt ! $($rest:tt)*
+	)=>{
+		$crate::eager_internal!{
+			@check_expansion[
+				[$lazy $modefix[$($prefix)* $t1 $t2 $t3 $t4][]]
+				$([$($rest_decoded)*])* $({$($config)*})? $(($($fuel)*))?
+			]
+			$t5 ! $($rest)*
+		}
+	};
+	(	// If the sixth token is '::', the token before it may start a path,
+		// but the ones before that are simple, so add them to the prefix all at once
+		@check_expansion[
+			[$lazy:tt $modefix:tt [$($prefix:tt)*][]]
+			$([$($rest_decoded:tt)*])* $({$($config:tt)*})? $((. $($fuel:tt)*))?
+		]
+		$t1:tt $t2:tt $t3:tt $t4:tt $t5:tt :: $($rest:tt)*
+	)=>{
+		$crate::eager_internal!{
+			@check_expansion[
+				[$lazy $modefix[$($prefix)* $t1 $t2 $t3 $t4][]]
+				$([$($rest_decoded)*])* $({$($config)*})? $(($($fuel)*))?
+			]
+			$t5 :: $($rest)*
+		}
+	};
+	(	// If the sixth token is '@', the tokens before it are simple,
+		// so add them to the prefix all at once
+		@check_expansion[
+			[$lazy:tt $modefix:tt [$($prefix:tt)*][]]
+			$([$($rest_decoded:tt)*])* $({$($config:tt)*})? $((. $($fuel:tt)*))?
+		]
+		$t1:tt $t2:tt $t3:tt $t4:tt $t5:tt @ $($rest:tt)*
+	)=>{
+		$crate::eager_internal!{
+			@check_expansion[
+				[$lazy $modefix[$($prefix)* $t1 $t2 $t3 $t4 $t5][]]
+				$([$($rest_decoded)*])* $({$($config)*})? $(($($fuel)*))?
+			]
+			@ $($rest)*
+		}
+	};
+	(	// If the seventh token is a block, the tokens before it are simple,
+		// so add them to the prefix all at once (brace type)
+		@check_expansion[
+			[$lazy:tt $modefix:tt [$($prefix:tt)*][]]
+			$([$($rest_decoded:tt)*])* $({$($config:tt)*})? $((. $($fuel:tt)*))?
+		]
+		$t1:tt $t2:tt $t3:tt $t4:tt $t5:tt $t6:tt {$($body:tt)*} $($rest:tt)*
+	)=>{
+		$crate::eager_internal!{
+			@check_expansion[
+				[$lazy $modefix[$($prefix)* $t1 $t2 $t3 $t4 $t5 $t6][]]
+				$([$($rest_decoded)*])* $({$($config)*})? $(($($fuel)*))?
+			]
+			{$($body)*} $($rest)*
+		}
+	};
+	(	// If the seventh token is a block, the tokens before it are simple,
+		// so add them to the prefix all at once (parenthesis type)
+		@check_expansion[
+			[$lazy:tt $modefix:tt [$($prefix:tt)*][]]
+			$([$($rest_decoded:tt)*])* $({$($config:tt)*})? $((. $($fuel:tt)*))?
 		]
-		eager!($($body:tt)*) $($rest:tt)*
+		$t1:tt $t2:tt $t3:tt $t4:tt $t5:tt $t6:tt ($($body:tt)*) $($rest:tt)*
 	)=>{
-		eager_internal!{
+		$crate::eager_internal!{
 			@check_expansion[
-				[[][$($rest)*][$($prefix)*][]]
-				$($rest_decoded)*
+				[$lazy $modefix[$($prefix)* $t1 $t2 $t3 $t4 $t5 $t6][]]
+				$([$($rest_decoded)*])* $({$($config)*})? $(($($fuel)*))?
 			]
-			$($body)*
+			($($body)*) $($rest)*
 		}
 	};
-	(	// If the next token is an 'lazy!' macro call and we are
-		// in eager mode, ignore it, extracting the body. (brace type)
+	(	// If the seventh token is a block, the tokens before it are simple,
+		// so add them to the prefix all at once (bracket type)
 		@check_expansion[
-			[[][][$($prefix:tt)*][]]
-			$($rest_decoded:tt)*
+			[$lazy:tt $modefix:tt [$($prefix:tt)*][]]
+			$([$($rest_decoded:tt)*])* $({$($config:tt)*})? $((. $($fuel:tt)*))?
 		]
-		lazy!{$($body:tt)*} $($rest:tt)*
+		$t1:tt $t2:tt $t3:tt $t4:tt $t5:tt $t6:tt [$($body:tt)*] $($rest:tt)*
 	)=>{
-		eager_internal!{
+		$crate::eager_internal!{
 			@check_expansion[
-				[[@lazy][$($rest)*][$($prefix)*][]]
-				$($rest_decoded)*
+				[$lazy $modefix[$($prefix)* $t1 $t2 $t3 $t4 $t5 $t6][]]
+				$([$($rest_decoded)*])* $({$($config)*})? $(($($fuel)*))?
 			]
-			$($body)*
+			[$($body)*] $($rest)*
 		}
 	};
-	(	// If the next token is an 'eager!' macro call and we are already
-		// in eager mode, ignore it, extracting the body. (parenthesis type)
+	(	// If the seventh token is '!', the token before it may start a path,
+		// but the ones before that are simple, so add them to the prefix all at once
 		@check_expansion[
-			[[][][$($prefix:tt)*][]]
-			$($rest_decoded:tt)*
+			[$lazy:tt $modefix:tt [$($prefix:tt)*][]]
+			$([$($rest_decoded:tt)*])* $({$($config:tt)*})? $((. $($fuel:tt)*))?
 		]
-		lazy!($($body:tt)*) $($rest:tt)*
+		$t1:tt $t2:tt $t3:tt $t4:tt $t5:tt $t6:tt ! $($rest:tt)*
 	)=>{
-		eager_internal!{
+		$crate::eager_internal!{
 			@check_expansion[
-				[[@lazy][$($rest)*][$($prefix)*][]]
-				$($rest_decoded)*
+				[$lazy $modefix[$($prefix)* $t1 $t2 $t3 $t4 $t5][]]
+				$([$($rest_decoded)*])* $({$($config)*})? $(($($fuel)*))?
 			]
-			$($body)*
+			$t6 ! $($rest)*
+		}
+	};
+	(	// If the seventh token is '::', the token before it may start a path,
+		// but the ones before that are simple, so add them to the prefix all at once
+		@check_expansion[
+			[$lazy:tt $modefix:tt [$($prefix:tt)*][]]
+			$([$($rest_decoded:tt)*])* $({$($config:tt)*})? $((. $($fuel:tt)*))?
+		]
+		$t1:tt $t2:tt $t3:tt $t4:tt $t5:tt $t6:tt :: $($rest:tt)*
+	)=>{
+		$crate::eager_internal!{
+			@check_expansion[
+				[$lazy $modefix[$($prefix)* $t1 $t2 $t3 $t4 $t5][]]
+				$([$($rest_decoded)*])* $({$($config)*})? $(($($fuel)*))?
+			]
+			$t6 :: $($rest)*
+		}
+	};
+	(	// If the seventh token is '@', the tokens before it are simple,
+		// so add them to the prefix all at once
+		@check_expansion[
+			[$lazy:tt $modefix:tt [$($prefix:tt)*][]]
+			$([$($rest_decoded:tt)*])* $({$($config:tt)*})? $((. $($fuel:tt)*))?
+		]
+		$t1:tt $t2:tt $t3:tt $t4:tt $t5:tt $t6:tt @ $($rest:tt)*
+	)=>{
+		$crate::eager_internal!{
+			@check_expansion[
+				[$lazy $modefix[$($prefix)* $t1 $t2 $t3 $t4 $t5 $t6][]]
+				$([$($rest_decoded)*])* $({$($config)*})? $(($($fuel)*))?
+			]
+			@ $($rest)*
+		}
+	};
+	(	// If the eighth token is a block, the tokens before it are simple,
+		// so add them to the prefix all at once (brace type)
+		@check_expansion[
+			[$lazy:tt $modefix:tt [$($prefix:tt)*][]]
+			$([$($rest_decoded:tt)*])* $({$($config:tt)*})? $((. $($fuel:tt)*))?
+		]
+		$t1:tt $t2:tt $t3:tt $t4:tt $t5:tt $t6:tt $t7:tt {$($body:tt)*} $($rest:tt)*
+	)=>{
+		$crate::eager_internal!{
+			@check_expansion[
+				[$lazy $modefix[$($prefix)* $t1 $t2 $t3 $t4 $t5 $t6 $t7][]]
+				$([$($rest_decoded)*])* $({$($config)*})? $(($($fuel)*))?
+			]
+			{$($body)*} $($rest)*
+		}
+	};
+	(	// If the eighth token is a block, the tokens before it are simple,
+		// so add them to the prefix all at once (parenthesis type)
+		@check_expansion[
+			[$lazy:tt $modefix:tt [$($prefix:tt)*][]]
+			$([$($rest_decoded:tt)*])* $({$($config:tt)*})? $((. $($fuel:tt)*))?
+		]
+		$t1:tt $t2:tt $t3:tt $t4:tt $t5:tt $t6:tt $t7:tt ($($body:tt)*) $($rest:tt)*
+	)=>{
+		$crate::eager_internal!{
+			@check_expansion[
+				[$lazy $modefix[$($prefix)* $t1 $t2 $t3 $t4 $t5 $t6 $t7][]]
+				$([$($rest_decoded)*])* $({$($config)*})? $(($($fuel)*))?
+			]
+			($($body)*) $($rest)*
+		}
+	};
+	(	// If the eighth token is a block, the tokens before it are simple,
+		// so add them to the prefix all at once (bracket type)
+		@check_expansion[
+			[$lazy:tt $modefix:tt [$($prefix:tt)*][]]
+			$([$($rest_decoded:tt)*])* $({$($config:tt)*})? $((. $($fuel:tt)*))?
+		]
+		$t1:tt $t2:tt $t3:tt $t4:tt $t5:tt $t6:tt $t7:tt [$($body:tt)*] $($rest:tt)*
+	)=>{
+		$crate::eager_internal!{
+			@check_expansion[
+				[$lazy $modefix[$($prefix)* $t1 $t2 $t3 $t4 $t5 $t6 $t7][]]
+				$([$($rest_decoded)*])* $({$($config)*})? $(($($fuel)*))?
+			]
+			[$($body)*] $($rest)*
+		}
+	};
+	(	// If the eighth token is '!', the token before it may start a path,
+		// but the ones before that are simple, so add them to the prefix all at once
+		@check_expansion[
+			[$lazy:tt $modefix:tt [$($prefix:tt)*][]]
+			$([$($rest_decoded:tt)*])* $({$($config:tt)*})? $((. $($fuel:tt)*))?
+		]
+		$t1:tt $t2:tt $t3:tt $t4:tt $t5:tt $t6:tt $t7:tt ! $($rest:tt)*
+	)=>{
+		$crate::eager_internal!{
+			@check_expansion[
+				[$lazy $modefix[$($prefix)* $t1 $t2 $t3 $t4 $t5 $t6][]]
+				$([$($rest_decoded)*])* $({$($config)*})? $(($($fuel)*))?
+			]
+			$t7 ! $($rest)*
+		}
+	};
+	(	// If the eighth token is '::', the token before it may start a path,
+		// but the ones before that are simple, so add them to the prefix all at once
+		@check_expansion[
+			[$lazy:tt $modefix:tt [$($prefix:tt)*][]]
+			$([$($rest_decoded:tt)*])* $({$($config:tt)*})? $((. $($fuel:tt)*))?
+		]
+		$t1:tt $t2:tt $t3:tt $t4:tt $t5:tt $t6:tt $t7:tt :: $($rest:tt)*
+	)=>{
+		$crate::eager_internal!{
+			@check_expansion[
+				[$lazy $modefix[$($prefix)* $t1 $t2 $t3 $t4 $t5 $t6][]]
+				$([$($rest_decoded)*])* $({$($config)*})? $(($($fuel)*))?
+			]
+			$t7 :: $($rest)*
+		}
+	};
+	(	// If the eighth token is '@', the tokens before it are simple,
+		// so add them to the prefix all at once
+		@check_expansion[
+			[$lazy:tt $modefix:tt [$($prefix:tt)*][]]
+			$([$($rest_decoded:tt)*])* $({$($config:tt)*})? $((. $($fuel:tt)*))?
+		]
+		$t1:tt $t2:tt $t3:tt $t4:tt $t5:tt $t6:tt $t7:tt @ $($rest:tt)*
+	)=>{
+		$crate::eager_internal!{
+			@check_expansion[
+				[$lazy $modefix[$($prefix)* $t1 $t2 $t3 $t4 $t5 $t6 $t7][]]
+				$([$($rest_decoded)*])* $({$($config)*})? $(($($fuel)*))?
+			]
+			@ $($rest)*
+		}
+	};
+	(	// If the ninth token is '!', the eighth token may start a path,
+		// but the ones before it are simple, so add them to the prefix all at once
+		@check_expansion[
+			[$lazy:tt $modefix:tt [$($prefix:tt)*][]]
+			$([$($rest_decoded:tt)*])* $({$($config:tt)*})? $((. $($fuel:tt)*))?
+		]
+		$t1:tt $t2:tt $t3:tt $t4:tt $t5:tt $t6:tt $t7:tt $t8:tt ! $($rest:tt)*
+	)=>{
+		$crate::eager_internal!{
+			@check_expansion[
+				[$lazy $modefix[$($prefix)* $t1 $t2 $t3 $t4 $t5 $t6 $t7][]]
+				$([$($rest_decoded)*])* $({$($config)*})? $(($($fuel)*))?
+			]
+			$t8 ! $($rest)*
+		}
+	};
+	(	// If the ninth token is '::', the eighth token may start a path,
+		// but the ones before it are simple, so add them to the prefix all at once
+		@check_expansion[
+			[$lazy:tt $modefix:tt [$($prefix:tt)*][]]
+			$([$($rest_decoded:tt)*])* $({$($config:tt)*})? $((. $($fuel:tt)*))?
+		]
+		$t1:tt $t2:tt $t3:tt $t4:tt $t5:tt $t6:tt $t7:tt $t8:tt :: $($rest:tt)*
+	)=>{
+		$crate::eager_internal!{
+			@check_expansion[
+				[$lazy $modefix[$($prefix)* $t1 $t2 $t3 $t4 $t5 $t6 $t7][]]
+				$([$($rest_decoded)*])* $({$($config)*})? $(($($fuel)*))?
+			]
+			$t8 :: $($rest)*
+		}
+	};
+	(	// Otherwise, the next eight tokens are all simple,
+		// so add them to the prefix all at once
+		@check_expansion[
+			[$lazy:tt $modefix:tt [$($prefix:tt)*][]]
+			$([$($rest_decoded:tt)*])* $({$($config:tt)*})? $((. $($fuel:tt)*))?
+		]
+		$t1:tt $t2:tt $t3:tt $t4:tt $t5:tt $t6:tt $t7:tt $t8:tt $($rest:tt)*
+	)=>{
+		$crate::eager_internal!{
+			@check_expansion[
+				[$lazy $modefix[$($prefix)* $t1 $t2 $t3 $t4 $t5 $t6 $t7 $t8][]]
+				$([$($rest_decoded)*])* $({$($config)*})? $(($($fuel)*))?
+			]
+			$($rest)*
+		}
+	};
+	(	// If there are fewer than eight tokens left, but at least four,
+		// add four of them to the prefix at once
+		@check_expansion[
+			[$lazy:tt $modefix:tt [$($prefix:tt)*][]]
+			$([$($rest_decoded:tt)*])* $({$($config:tt)*})? $((. $($fuel:tt)*))?
+		]
+		$t1:tt $t2:tt $t3:tt $t4:tt $($rest:tt)*
+	)=>{
+		$crate::eager_internal!{
+			@check_expansion[
+				[$lazy $modefix[$($prefix)* $t1 $t2 $t3 $t4][]]
+				$([$($rest_decoded)*])* $({$($config)*})? $(($($fuel)*))?
+			]
+			$($rest)*
+		}
+	};
+	(	// If there are fewer than eight tokens left, but at least two,
+		// add two of them to the prefix at once
+		@check_expansion[
+			[$lazy:tt $modefix:tt [$($prefix:tt)*][]]
+			$([$($rest_decoded:tt)*])* $({$($config:tt)*})? $((. $($fuel:tt)*))?
+		]
+		$t1:tt $t2:tt $($rest:tt)*
+	)=>{
+		$crate::eager_internal!{
+			@check_expansion[
+				[$lazy $modefix[$($prefix)* $t1 $t2][]]
+				$([$($rest_decoded)*])* $({$($config)*})? $(($($fuel)*))?
+			]
+			$($rest)*
 		}
 	};
-// end eager/lazy mode switches
 	(	// If the next token isn't any of the above
 		// it is safe to add it to the prefix
 		@check_expansion[
 			[$lazy:tt $modefix:tt [$($prefix:tt)*][]]
-			$($rest_decoded:tt)*
+			$([$($rest_decoded:tt)*])* $({$($config:tt)*})? $((. $($fuel:tt)*))?
 		]
 		$next:tt $($rest:tt)*
 	)=>{
-		eager_internal!{
+		$crate::eager_internal!{
 			@check_expansion[
-				[$lazy $modefix[$next $($prefix)*][]]
-				$($rest_decoded)*
+				[$lazy $modefix[$($prefix)* $next][]]
+				$([$($rest_decoded)*])* $({$($config)*})? $(($($fuel)*))?
 			]
 			$($rest)*
 		}
 	};
+// end simple tokens
 // Done decoding input
 // Expanding macros in eager mode
-	(	// When there is no more input, the last input was a macro call,
-		// and we are in eager mode, call the macro eagerly
-		// (brace type)
+	(	// When there is no more input and the block is the input to a macro call,
+		// add the level to the trace, use the fuel for the call if given,
+		// and call the macro eagerly (brace type)
 		@check_expansion[
-			[[]$modefix:tt[! $macro_name:tt $($prefix:tt)*][$($postfix:tt)*]{$($body:tt)*}]
-			$($rest_decoded:tt)*
+			[[]$modefix:tt $prefix:tt $postfix:tt {$($body:tt)*} [$($macro_path:tt)+]]
+			$([$($level:tt)*])*
+			{$(@depth $depth:tt)? $(@filter $filter:tt)? $(@into $into:tt)? @trace[$($trace:tt)*]}
+			$((. . . . . $($fuel:tt)*))?
 		]
 	)=>{
-		$macro_name!{
-			@eager[
-				[[]$modefix[$($prefix)*][$($postfix)*]]
-				$($rest_decoded)*
+		$($macro_path)+!{
+			@__dmutil_eager[
+				@__dmutil_version[1] [$crate::eager_internal]
+				[[]$modefix $prefix $postfix]
+				$([$($level)*])*
+				{
+					$(@depth $depth)?
+					$(@filter $filter)?
+					$(@into $into)?
+					@trace[
+						$($trace)*
+						stringify!([[]$modefix $prefix $postfix {$($body)*} [$($macro_path)+]]),
+					]
+				}
+				$(($($fuel)*))?
 			]
 			$($body)*
 		}
 	};
-	(	// When there is no more input and the last input was a macro call,
-		// and we are in eager mode, call the macro eagerly
-		// (parenthesis type)
+	(	// When there is no more input and the block is the input to a macro call,
+		// add the level to the trace, use the fuel for the call if given,
+		// and call the macro eagerly (parenthesis type)
 		@check_expansion[
-			[[]$modefix:tt[! $macro_name:tt $($prefix:tt)*][$($postfix:tt)*]($($body:tt)*)]
-			$($rest_decoded:tt)*
+			[[]$modefix:tt $prefix:tt $postfix:tt ($($body:tt)*) [$($macro_path:tt)+]]
+			$([$($level:tt)*])*
+			{$(@depth $depth:tt)? $(@filter $filter:tt)? $(@into $into:tt)? @trace[$($trace:tt)*]}
+			$((. . . . . $($fuel:tt)*))?
 		]
 	)=>{
-		$macro_name!{
-			@eager[
-				[[]$modefix[$($prefix)*][$($postfix)*]]
-				$($rest_decoded)*
+		$($macro_path)+!{
+			@__dmutil_eager[
+				@__dmutil_version[1] [$crate::eager_internal]
+				[[]$modefix $prefix $postfix]
+				$([$($level)*])*
+				{
+					$(@depth $depth)?
+					$(@filter $filter)?
+					$(@into $into)?
+					@trace[
+						$($trace)*
+						stringify!([[]$modefix $prefix $postfix ($($body)*) [$($macro_path)+]]),
+					]
+				}
+				$(($($fuel)*))?
+			]
+			$($body)*
+		}
+	};
+	(	// When there is no more input and the block is the input to a macro call,
+		// add the level to the trace, use the fuel for the call if given,
+		// and call the macro eagerly (bracket type)
+		@check_expansion[
+			[[]$modefix:tt $prefix:tt $postfix:tt [$($body:tt)*] [$($macro_path:tt)+]]
+			$([$($level:tt)*])*
+			{$(@depth $depth:tt)? $(@filter $filter:tt)? $(@into $into:tt)? @trace[$($trace:tt)*]}
+			$((. . . . . $($fuel:tt)*))?
+		]
+	)=>{
+		$($macro_path)+!{
+			@__dmutil_eager[
+				@__dmutil_version[1] [$crate::eager_internal]
+				[[]$modefix $prefix $postfix]
+				$([$($level)*])*
+				{
+					$(@depth $depth)?
+					$(@filter $filter)?
+					$(@into $into)?
+					@trace[
+						$($trace)*
+						stringify!([[]$modefix $prefix $postfix [$($body)*] [$($macro_path)+]]),
+					]
+				}
+				$(($($fuel)*))?
+			]
+			$($body)*
+		}
+	};
+	(	// When there is no more input and the block is the input to a macro call,
+		// use the fuel for the call if given, and call the macro eagerly (brace type)
+		@check_expansion[
+			[[]$modefix:tt $prefix:tt $postfix:tt {$($body:tt)*} [$($macro_path:tt)+]]
+			$([$($rest_decoded:tt)*])* $({$($config:tt)*})? $((. . . . . $($fuel:tt)*))?
+		]
+	)=>{
+		$($macro_path)+!{
+			@__dmutil_eager[
+				@__dmutil_version[1] [$crate::eager_internal]
+				[[]$modefix $prefix $postfix]
+				$([$($rest_decoded)*])* $({$($config)*})? $(($($fuel)*))?
+			]
+			$($body)*
+		}
+	};
+	(	// When there is no more input and the block is the input to a macro call,
+		// use the fuel for the call if given, and call the macro eagerly (parenthesis type)
+		@check_expansion[
+			[[]$modefix:tt $prefix:tt $postfix:tt ($($body:tt)*) [$($macro_path:tt)+]]
+			$([$($rest_decoded:tt)*])* $({$($config:tt)*})? $((. . . . . $($fuel:tt)*))?
+		]
+	)=>{
+		$($macro_path)+!{
+			@__dmutil_eager[
+				@__dmutil_version[1] [$crate::eager_internal]
+				[[]$modefix $prefix $postfix]
+				$([$($rest_decoded)*])* $({$($config)*})? $(($($fuel)*))?
+			]
+			$($body)*
+		}
+	};
+	(	// When there is no more input and the block is the input to a macro call,
+		// use the fuel for the call if given, and call the macro eagerly (bracket type)
+		@check_expansion[
+			[[]$modefix:tt $prefix:tt $postfix:tt [$($body:tt)*] [$($macro_path:tt)+]]
+			$([$($rest_decoded:tt)*])* $({$($config:tt)*})? $((. . . . . $($fuel:tt)*))?
+		]
+	)=>{
+		$($macro_path)+!{
+			@__dmutil_eager[
+				@__dmutil_version[1] [$crate::eager_internal]
+				[[]$modefix $prefix $postfix]
+				$([$($rest_decoded)*])* $({$($config)*})? $(($($fuel)*))?
 			]
 			$($body)*
 		}
 	};
+	(	// If there is too little fuel left to call the macro and return from it,
+		// stop with an error
+		@check_expansion[
+			[[]$modefix:tt $prefix:tt [$($postfix:tt)*] $block:tt [$($macro_path:tt)+]]
+			$([$($level:tt)*])*
+			$({$($config:tt)*})?
+			($($fuel:tt)*)
+		]
+	)=>{
+		compile_error!{
+			concat!(
+				"eager!: ran out of fuel before expanding '", stringify!($($macro_path)+!),
+				"'. Remaining input: '", stringify!($($macro_path)+!$block $($postfix)*), "'"
+			)
+		}
+	};
 // Promote modefix to input
 	(	// When there is no more input, but there is some postfix,
 		// if the current mode is eager, redecode the postfix in lazy mode
 		@check_expansion[
 			[[][$($modefix:tt)+] $prefix:tt []]
-			$($rest:tt)*
+			$([$($rest:tt)*])* $({$($config:tt)*})? $((. $($fuel:tt)*))?
 		]
 	)=>{
-		eager_internal!{
+		$crate::eager_internal!{
 			@check_expansion[
 				[[@lazy][] $prefix []]
-				$($rest)*
+				$([$($rest)*])* $({$($config)*})? $(($($fuel)*))?
 			]
 			$($modefix)+
 		}
@@ -679,109 +4134,331 @@ macro_rules! eager_internal{
 		// if the current mode is lazy, redecode the postfix in eager mode
 		@check_expansion[
 			[[@lazy][$($modefix:tt)+] $prefix:tt []]
-			$($rest:tt)*
+			$([$($rest:tt)*])* $({$($config:tt)*})? $((. $($fuel:tt)*))?
 		]
 	)=>{
-		eager_internal!{
+		$crate::eager_internal!{
 			@check_expansion[
 				[[][] $prefix []]
-				$($rest)*
+				$([$($rest)*])* $({$($config)*})? $(($($fuel)*))?
 			]
 			$($modefix)+
 		}
 	};
 // end Promote modefix to input
 // Promote prefix
-	(	// When there is no more input and the last input wasn't a macro call in eager mode
-		// insert it into the previous block (brace type)
+	(	// When there is no more input, modefix or postfix, the contents of the block
+		// in the previous level have been checked, and are all in the prefix.
+		// If the block isn't the input to a macro call, promote it to the prefix of
+		// the previous level, and continue with its postfix (brace type)
 		@check_expansion[
-			[$lazy_0:tt $modefix_0:tt [$last:tt $($last_rest:tt)*] []]
-			[$lazy:tt $modefix:tt $prefix:tt $postfix:tt {$($body:tt)*}]
-			$($rest:tt)*
+			[$lazy_0:tt [] [$($checked:tt)*] []]
+			[$lazy:tt $modefix:tt [$($prefix:tt)*] [$($postfix:tt)*] {}]
+			$([$($rest:tt)*])* $({$($config:tt)*})? $((. $($fuel:tt)*))?
 		]
 	)=>{
-		eager_internal!{
+		$crate::eager_internal!{
 			@check_expansion[
-				[$lazy_0 $modefix_0 [$($last_rest)*] []]
-				[$lazy $modefix $prefix $postfix {$last $($body)*}]
-				$($rest)*
+				[$lazy $modefix [$($prefix)* {$($checked)*}] []]
+				$([$($rest)*])* $({$($config)*})? $(($($fuel)*))?
 			]
+			$($postfix)*
 		}
 	};
-	(	// When there is no more input and the last input wasn't a macro call in eager mode
-		// insert it into the previous block (parenthesis type)
+	(	// When there is no more input, modefix or postfix, the contents of the block
+		// in the previous level have been checked, and are all in the prefix.
+		// If the block isn't the input to a macro call, promote it to the prefix of
+		// the previous level, and continue with its postfix (parenthesis type)
 		@check_expansion[
-			[$lazy_0:tt $modefix_0:tt[$last:tt $($last_rest:tt)*] []]
-			[$lazy:tt $modefix:tt $prefix:tt $postfix:tt ($($body:tt)*)]
-			$($rest:tt)*
+			[$lazy_0:tt [] [$($checked:tt)*] []]
+			[$lazy:tt $modefix:tt [$($prefix:tt)*] [$($postfix:tt)*] ()]
+			$([$($rest:tt)*])* $({$($config:tt)*})? $((. $($fuel:tt)*))?
 		]
 	)=>{
-		eager_internal!{
+		$crate::eager_internal!{
 			@check_expansion[
-				[$lazy_0 $modefix_0 [$($last_rest)*] []]
-				[$lazy $modefix $prefix $postfix ($last $($body)*)]
-				$($rest)*
+				[$lazy $modefix [$($prefix)* ($($checked)*)] []]
+				$([$($rest)*])* $({$($config)*})? $(($($fuel)*))?
 			]
+			$($postfix)*
 		}
 	};
-	(	// When there is no more input, prefix or postfix,
-		// but there is a previous block, remove the input catcher
+	(	// When there is no more input, modefix or postfix, the contents of the block
+		// in the previous level have been checked, and are all in the prefix.
+		// If the block isn't the input to a macro call, promote it to the prefix of
+		// the previous level, and continue with its postfix (bracket type)
 		@check_expansion[
-			[$lazy_0:tt[][][]]
-			$([$lazy:tt $modefix:tt $prefix:tt $postfix:tt $body:tt])+
-			
+			[$lazy_0:tt [] [$($checked:tt)*] []]
+			[$lazy:tt $modefix:tt [$($prefix:tt)*] [$($postfix:tt)*] []]
+			$([$($rest:tt)*])* $({$($config:tt)*})? $((. $($fuel:tt)*))?
 		]
 	)=>{
-		eager_internal!{
+		$crate::eager_internal!{
 			@check_expansion[
-				$([$lazy $modefix $prefix $postfix $body])+
+				[$lazy $modefix [$($prefix)* [$($checked)*]] []]
+				$([$($rest)*])* $({$($config)*})? $(($($fuel)*))?
 			]
+			$($postfix)*
 		}
 	};
-// end promote prefix
-// Promote block to prefix
-	(	// When there is no more input but a block,
-		// the block must have already been checked,
-		// therefore, begin promoting to prefix (brace type)
+	(	// Otherwise, just put the contents in the block, such that the macro can be called
+		// with it (brace type)
 		@check_expansion[
-			[$lazy:tt $modefix:tt [$($prefix:tt)*][$($postfix:tt)*]{$($body:tt)*}]
-			$($rest:tt)*
+			[$lazy_0:tt [] [$($checked:tt)*] []]
+			[$lazy:tt $modefix:tt $prefix:tt $postfix:tt {} $macro_path:tt]
+			$([$($rest:tt)*])* $({$($config:tt)*})? $((. $($fuel:tt)*))?
 		]
 	)=>{
-		eager_internal!{
+		$crate::eager_internal!{
 			@check_expansion[
-				[$lazy $modefix [{$($body)*} $($prefix)*][]]
-				$($rest)*
+				[$lazy $modefix $prefix $postfix {$($checked)*} $macro_path]
+				$([$($rest)*])* $({$($config)*})? $(($($fuel)*))?
 			]
-			$($postfix)*
 		}
 	};
-	(	// When there is no more input and but a block
-		// the block must have already been checked,
-		// so output everything (parenthesis type)
+	(	// Otherwise, just put the contents in the block, such that the macro can be called
+		// with it (parenthesis type)
 		@check_expansion[
-			[$lazy:tt $modefix:tt [$($prefix:tt)*][$($postfix:tt)*]($($body:tt)*)]
-			$($rest:tt)*
+			[$lazy_0:tt [] [$($checked:tt)*] []]
+			[$lazy:tt $modefix:tt $prefix:tt $postfix:tt () $macro_path:tt]
+			$([$($rest:tt)*])* $({$($config:tt)*})? $((. $($fuel:tt)*))?
 		]
 	)=>{
-		eager_internal!{
+		$crate::eager_internal!{
 			@check_expansion[
-				[$lazy $modefix [($($body)*) $($prefix)*][]]
-				$($rest)*
+				[$lazy $modefix $prefix $postfix ($($checked)*) $macro_path]
+				$([$($rest)*])* $({$($config)*})? $(($($fuel)*))?
+			]
+		}
+	};
+	(	// Otherwise, just put the contents in the block, such that the macro can be called
+		// with it (bracket type)
+		@check_expansion[
+			[$lazy_0:tt [] [$($checked:tt)*] []]
+			[$lazy:tt $modefix:tt $prefix:tt $postfix:tt [] $macro_path:tt]
+			$([$($rest:tt)*])* $({$($config:tt)*})? $((. $($fuel:tt)*))?
+		]
+	)=>{
+		$crate::eager_internal!{
+			@check_expansion[
+				[$lazy $modefix $prefix $postfix [$($checked)*] $macro_path]
+				$([$($rest)*])* $({$($config)*})? $(($($fuel)*))?
 			]
-			$($postfix)*
 		}
 	};
-// End Promote block to prefix
+// end promote prefix
 // Finished
-	(	// When there is no more input and no block
-		// output the result, reversing it to ensure correct order
+	(	// When there is no more input and no block, output the trace
+		// including the final level, instead of the result
+		@check_expansion[
+			[$lazy:tt [] $prefix:tt []]
+			{$(@depth $depth:tt)? $(@filter $filter:tt)? $(@into $into:tt)? @trace[$($trace:tt)*]}
+			$(($($fuel:tt)*))?
+		]
+	)=>{
+		{
+			const TRACE: &[&str] = &[
+				$($trace)*
+				stringify!([$lazy [] $prefix []]),
+			];
+			TRACE
+		}
+	};
+	(	// When there is no more input and no block, give the result to
+		// the macro from '@into' instead of outputting it
+		@check_expansion[
+			[$lazy:tt [][$($result:tt)*][]]
+			{$(@depth $depth:tt)? $(@filter $filter:tt)? @into[$($into:tt)*]}
+			$(($($fuel:tt)*))?
+		]
+	)=>{
+		$($into)*{$($result)*}
+	};
+	(	// When there is no more input and no block, output the result
 		@check_expansion[
 			[$lazy:tt [][$($result:tt)*][]]
+			$({$($config:tt)*})?
+			$(($($fuel:tt)*))?
+		]
+	)=>{
+		$($result)*
+	};
+// Out of fuel
+	(	// If the fuel has run out while checking the input to a macro, stop with an error
+		// naming the macro
+		@check_expansion[
+			[$lazy_0:tt $modefix_0:tt $prefix_0:tt [$($postfix_0:tt)*]]
+			[$lazy:tt $modefix:tt $prefix:tt $postfix:tt $block:tt [$($macro_path:tt)+]]
+			$([$($level:tt)*])*
+			$({$($config:tt)*})?
+			()
+		]
+		$($input:tt)*
+	)=>{
+		compile_error!{
+			concat!(
+				"eager!: ran out of fuel while checking the input to '", stringify!($($macro_path)+!),
+				"'. Remaining input: '", stringify!($($input)* $($postfix_0)*), "'"
+			)
+		}
+	};
+	(	// Otherwise, just say what input remains
+		@check_expansion[
+			[$lazy:tt $modefix:tt $prefix:tt [$($postfix:tt)*]]
+			$([$($level:tt)*])*
+			$({$($config:tt)*})?
+			()
 		]
+		$($input:tt)*
 	)=>{
-		reverse_tt!{[$($result)*]}
+		compile_error!{
+			concat!(
+				"eager!: ran out of fuel. Remaining input: '", stringify!($($input)* $($postfix)*), "'"
+			)
+		}
 	};
+// end out of fuel
 }
 
 
+
+/*
+Converts the value given to an option of 'eager!', e.g. '@fuel[2 0 0 0]', from its digits to unary.
+The input is the digits left, the value in unary so far, then the option, its value and
+the options so far, followed by the input to 'eager!'. Each digit multiplies the value so far
+by 10 before adding itself, so a value of any number of digits can be converted.
+When done, the unary value is given back to 'eager_internal!' as '@unary[option [unary] options]'.
+*/
+#[macro_export]
+#[doc(hidden)]
+macro_rules! eager_unary_internal{
+	(	// No value was given
+		$digits:tt $unary:tt [$option:ident [] $options:tt] $($all:tt)*
+	)=>{
+		compile_error!{
+			concat!(
+				"eager!: '@", stringify!($option), "' must be given a number, e.g. '@",
+				stringify!($option), "[1 2 0]'"
+			)
+		}
+	};
+	(	// Multiply the value so far by 10 and add 0
+		[0 $($digits:tt)*] [$($unary:tt)*] $($return:tt)*
+	)=>{
+		$crate::eager_unary_internal!{
+			[$($digits)*] [
+				$($unary)* $($unary)* $($unary)* $($unary)* $($unary)*
+				$($unary)* $($unary)* $($unary)* $($unary)* $($unary)*
+			] $($return)*
+		}
+	};
+	(	// Multiply the value so far by 10 and add 1
+		[1 $($digits:tt)*] [$($unary:tt)*] $($return:tt)*
+	)=>{
+		$crate::eager_unary_internal!{
+			[$($digits)*] [
+				$($unary)* $($unary)* $($unary)* $($unary)* $($unary)*
+				$($unary)* $($unary)* $($unary)* $($unary)* $($unary)* .
+			] $($return)*
+		}
+	};
+	(	// Multiply the value so far by 10 and add 2
+		[2 $($digits:tt)*] [$($unary:tt)*] $($return:tt)*
+	)=>{
+		$crate::eager_unary_internal!{
+			[$($digits)*] [
+				$($unary)* $($unary)* $($unary)* $($unary)* $($unary)*
+				$($unary)* $($unary)* $($unary)* $($unary)* $($unary)* . .
+			] $($return)*
+		}
+	};
+	(	// Multiply the value so far by 10 and add 3
+		[3 $($digits:tt)*] [$($unary:tt)*] $($return:tt)*
+	)=>{
+		$crate::eager_unary_internal!{
+			[$($digits)*] [
+				$($unary)* $($unary)* $($unary)* $($unary)* $($unary)*
+				$($unary)* $($unary)* $($unary)* $($unary)* $($unary)* . . .
+			] $($return)*
+		}
+	};
+	(	// Multiply the value so far by 10 and add 4
+		[4 $($digits:tt)*] [$($unary:tt)*] $($return:tt)*
+	)=>{
+		$crate::eager_unary_internal!{
+			[$($digits)*] [
+				$($unary)* $($unary)* $($unary)* $($unary)* $($unary)*
+				$($unary)* $($unary)* $($unary)* $($unary)* $($unary)* . . . .
+			] $($return)*
+		}
+	};
+	(	// Multiply the value so far by 10 and add 5
+		[5 $($digits:tt)*] [$($unary:tt)*] $($return:tt)*
+	)=>{
+		$crate::eager_unary_internal!{
+			[$($digits)*] [
+				$($unary)* $($unary)* $($unary)* $($unary)* $($unary)*
+				$($unary)* $($unary)* $($unary)* $($unary)* $($unary)* . . . . .
+			] $($return)*
+		}
+	};
+	(	// Multiply the value so far by 10 and add 6
+		[6 $($digits:tt)*] [$($unary:tt)*] $($return:tt)*
+	)=>{
+		$crate::eager_unary_internal!{
+			[$($digits)*] [
+				$($unary)* $($unary)* $($unary)* $($unary)* $($unary)*
+				$($unary)* $($unary)* $($unary)* $($unary)* $($unary)* . . . . . .
+			] $($return)*
+		}
+	};
+	(	// Multiply the value so far by 10 and add 7
+		[7 $($digits:tt)*] [$($unary:tt)*] $($return:tt)*
+	)=>{
+		$crate::eager_unary_internal!{
+			[$($digits)*] [
+				$($unary)* $($unary)* $($unary)* $($unary)* $($unary)*
+				$($unary)* $($unary)* $($unary)* $($unary)* $($unary)* . . . . . . .
+			] $($return)*
+		}
+	};
+	(	// Multiply the value so far by 10 and add 8
+		[8 $($digits:tt)*] [$($unary:tt)*] $($return:tt)*
+	)=>{
+		$crate::eager_unary_internal!{
+			[$($digits)*] [
+				$($unary)* $($unary)* $($unary)* $($unary)* $($unary)*
+				$($unary)* $($unary)* $($unary)* $($unary)* $($unary)* . . . . . . . .
+			] $($return)*
+		}
+	};
+	(	// Multiply the value so far by 10 and add 9
+		[9 $($digits:tt)*] [$($unary:tt)*] $($return:tt)*
+	)=>{
+		$crate::eager_unary_internal!{
+			[$($digits)*] [
+				$($unary)* $($unary)* $($unary)* $($unary)* $($unary)*
+				$($unary)* $($unary)* $($unary)* $($unary)* $($unary)* . . . . . . . . .
+			] $($return)*
+		}
+	};
+	(	// When all digits have been converted, give the value back
+		[] [$($unary:tt)*] [$option:ident $value:tt $options:tt] $($all:tt)*
+	)=>{
+		$crate::eager_internal!{
+			@unary[$option [$($unary)*] $options]
+			$($all)*
+		}
+	};
+	(	// Otherwise, the value isn't a number with its digits separated
+		$digits:tt $unary:tt [$option:ident [$($value:tt)*] $options:tt] $($all:tt)*
+	)=>{
+		compile_error!{
+			concat!(
+				"eager!: the value of '@", stringify!($option), "' must be a number with its digits ",
+				"separated by spaces, e.g. '@", stringify!($option), "[1 2 0]', but was '",
+				stringify!($($value)*), "'"
+			)
+		}
+	};
+}
@@ -4,18 +4,25 @@
 ///
 /// # Usage
 ///
-/// Wraps the usual `macro_rules!` syntax. First an identifier must be given, preceded by '$'.
+/// Wraps the usual `macro_rules!` syntax. First a `$` must be given, which is used to declare
+/// the macro variables that `eager!` needs in the declared macros.
 /// Then any number of macro declarations can be given using the usual `macro_rules!` syntax.
+/// Like with `macro_rules!`, the rules can be in braces, parentheses or brackets,
+/// e.g. `macro_rules! some_macro( ... );`.
 /// Documentation and attributes are also given in the
 /// usual way just before each `macro_rules!`, i.e. inside `eager_macro_rules!`.
 ///
 /// Some restrictions apply to the `macro_rules!` declarations:
 ///
-/// * The identifier given at the beginning must not collide with any macro variable name
-/// used in any rule in any macro to be declared.
-/// * No rules should accept `@eager` as the first token, as this could conflict with the
-/// implementation of `eager!`. Wildcards are acceptable, as `eager_macro_rules!` will automatically
-/// resolve the ambiguity with the `eager!` implementation.
+/// * No rules should accept `@__dmutil_eager`, `@__dmutil_eager_args` or `@__dmutil_is`
+/// as the first token, or the message `assert_eager_enabled!` gives as its question, as this could
+/// conflict with the implementation of `eager!`. Wildcards are acceptable, as `eager_macro_rules!` will automatically resolve the
+/// ambiguity with the `eager!` implementation. Rules accepting `@eager`, which earlier versions of `eager!` used,
+/// are also fine.
+///
+/// Previously, an auxiliary variable, e.g. `$eager_1`, had to be given instead of just the `$`,
+/// and no macro variable could have the same name. This form is still accepted, but the
+/// identifier is ignored, so the macros may use any names for their macro variables.
 ///
 /// # `eager!`-enabling example
 ///
@@ -30,7 +37,7 @@
 /// is done by wrapping it in `eager_macro_rules!` as follows:
 /// ```
 /// #[macro_use] extern crate dmutil;
-/// eager_macro_rules!{ $eager_1
+/// eager_macro_rules!{ $
 /// 	/// Some documentation
 ///     #[macro_export]
 ///     macro_rules! some_macro{
@@ -38,164 +45,733 @@
 ///     }
 /// }
 /// ```
-/// where `()=>{};` is the list of rules that comprise the macro, and no macro variable is called
-/// `$eager_1`.
+/// where `()=>{};` is the list of rules that comprise the macro.
+///
+/// # Options
+///
+/// The `#[eager(...)]` attribute changes how a macro behaves inside `eager!`. It can be given
+/// to a whole macro, just before `macro_rules!`, or to a single rule, just before its grammar.
+/// The following options are available:
+///
+/// * `lazy_args`: The input is given to the macro without any of the macro calls in it being
+/// expanded first, as if the input had been wrapped in `lazy!`. The expansion of the macro is
+/// still eagerly expanded. This is useful for macros that need their raw input,
+/// e.g. macros that stringify their input or declare other macros.
+/// When a macro has both rules with and without this option, the rules with it are tried first.
+///
+/// * `no_rescan`: The expansion of the macro is not checked for macro calls, but is output as is.
+/// This is faster for macros with large expansions that are known not to contain calls to
+/// `eager!`-enabled macros, and is needed if the expansion must not be touched,
+/// e.g. if it contains a `lazy!` or `eager!` that is meant literally.
+///
+/// * `only`: The macro can only be used inside `eager!`, so only the `eager!` versions of the rules
+/// are declared, which makes the macro faster to declare and use. Used outside `eager!`,
+/// the macro emits an error saying so. A macro with `N` rules is usually declared with `3N + 7`
+/// rules: an `eager!` version, the usual version and a version for earlier versions of `eager!`
+/// of each rule, and seven rules used by `eager!` itself. With this option, it is instead
+/// declared with `2N + 8` rules, the last one giving the error.
+///
+/// Multiple options can be given in the same attribute, e.g. `#[eager(lazy_args, no_rescan)]`.
+///
+/// Unlike in `macro_rules!`, a rule can also be given `#[cfg(...)]`, such that it only exists
+/// when the configuration predicate holds, e.g. `#[cfg(feature = "some_feature")]`.
+/// This applies to both the usual and the `eager!` version of the rule. Since `macro_rules!`
+/// does not allow this, the rest of the rules are decoded by two macro calls, one with the rule
+/// and `#[cfg(...)]`, and one without it and with `#[cfg(not(...))]`, so only one of them is
+/// expanded and the macro is declared once. Likewise, `#[cfg_attr(...)]` can give a rule
+/// any of these attributes if a predicate holds, e.g. `#[cfg_attr(test, eager(no_rescan))]`.
+/// Any other attribute on a rule, e.g. documentation, is an error, since it would have no effect.
+///
+/// ```
+/// #[macro_use] extern crate dmutil;
+/// eager_macro_rules!{ $eager_1
+///     macro_rules! one{
+///         ()=>{1};
+///     }
+///     #[eager(lazy_args)]
+///     macro_rules! is_call{
+///         ($name:ident ! $args:tt)=>{1};
+///         ($($other:tt)*)=>{0};
+///     }
+/// }
+/// fn main(){
+///     assert_eq!(1, eager!{is_call!(one!())});
+/// }
+/// ```
+///
+/// # Protocol
+///
+/// Inside `eager!`, an `eager!`-enabled macro is called with its input prefixed by the
+/// state of `eager!`, e.g. `some_macro!{@__dmutil_eager[...] input}`, and must return that state
+/// together with its expansion. The state starts with `@__dmutil_version[1]`, the version of the
+/// protocol, followed by the path to return to in brackets, e.g. `[$crate::eager_internal]`.
+/// Macros declared with `eager_macro_rules!` return to the `dmutil` they were declared with,
+/// which checks that the version is its own, so mixing incompatible versions of `dmutil`
+/// gives an error saying so instead of failing somewhere inside `eager!`.
+///
+/// Such macros are first given their input before it is checked, in case they have `lazy_args`
+/// rules. If none accept it, and the state is from the version they were declared with,
+/// they start checking the input for `eager!` right away, or if there is no input,
+/// go straight to their other rules, instead of giving the input back to be checked.
+/// This way, macros without `lazy_args` rules need no more recursion depth than
+/// if `eager!` had checked the input first.
+///
+/// The following subset of the protocol is stable, such that a crate can `eager!`-enable
+/// a macro by hand without depending on `dmutil`. For each rule `(grammar) => {expansion}`,
+/// the macro gets the following rule, placed before all the usual rules:
+/// ```text
+/// (@__dmutil_eager[@__dmutil_version $version:tt [$($return:tt)*] $($state:tt)*] grammar) => {
+///     $($return)*!{@from_macro[@__dmutil_version $version [$($return)*] $($state)*] expansion}
+/// };
+/// ```
+/// and, also before the usual rules, the rule:
+/// ```text
+/// (@__dmutil_eager_args[$($state:tt)*] $($input:tt)*) => {
+///     ...!{@scan_args[$($state)*] $($input)*}
+/// };
+/// ```
+/// where `...` is the return path from the state. Since the state is returned to the `eager!`
+/// that made it, the version never mismatches. The metavariable names of these rules must not be
+/// used in the grammar. `@only`, `@except` and `assert_eager_enabled!` are not supported by
+/// such macros.
+///
+/// ```
+/// #[macro_use] extern crate dmutil;
+/// macro_rules! two_and_three{
+///     (@__dmutil_eager[@__dmutil_version $version:tt [$($return:tt)*] $($state:tt)*]) => {
+///         $($return)*!{@from_macro[@__dmutil_version $version [$($return)*] $($state)*] 2, 3}
+///     };
+///     (
+///         @__dmutil_eager_args[@__dmutil_version $version:tt [$($return:tt)*] $($state:tt)*]
+///         $($input:tt)*
+///     ) => {
+///         $($return)*!{@scan_args[@__dmutil_version $version [$($return)*] $($state)*] $($input)*}
+///     };
+///     () => {2, 3};
+/// }
+/// eager_macro_rules!{ $
+///     macro_rules! add{
+///         ($e1:expr, $e2:expr)=>{$e1 + $e2};
+///     }
+/// }
+/// fn main(){
+///     assert_eq!(5, eager!{add!(two_and_three!())});
+/// }
+/// ```
+///
+/// # Errors
+///
+/// If a macro declared with one version of `dmutil` is given the state of an incompatible version
+/// of `eager!`, the error says so:
+/// ```compile_fail
+/// #[macro_use] extern crate dmutil;
+/// eager_macro_rules!{ $
+///     macro_rules! some_macro{
+///         ()=>{};
+///     }
+/// }
+/// fn main(){
+///     // error: eager!: a macro was called using version 2 of the eager! protocol,
+///     // but was declared using version 1. Make sure all crates use compatible versions of dmutil
+///     some_macro!{@__dmutil_eager[@__dmutil_version[2] [] [[][][][]]]};
+/// }
+/// ```
+///
+/// The version is also checked when a macro replies whether it is one given to `@only` or `@except`:
+/// ```compile_fail
+/// #[macro_use] extern crate dmutil;
+/// eager_macro_rules!{ $
+///     macro_rules! some_macro{
+///         ()=>{};
+///     }
+/// }
+/// fn main(){
+///     // error: eager!: a macro was called using version 2 of the eager! protocol,
+///     // but was declared using version 1. Make sure all crates use compatible versions of dmutil
+///     some_macro!{@__dmutil_is[@__dmutil_version[2] [dmutil::eager_internal] some_macro] [] []};
+/// }
+/// ```
+///
+/// Likewise, a macro must return the state with its version, or `eager!` won't accept it:
+/// ```compile_fail
+/// #[macro_use] extern crate dmutil;
+/// macro_rules! unversioned{
+///     (@__dmutil_eager[@__dmutil_version $version:tt [$($return:tt)*] $($state:tt)*]) => {
+///         $($return)*!{@from_macro[$($state)*] 1}
+///     };
+///     (
+///         @__dmutil_eager_args[@__dmutil_version $version:tt [$($return:tt)*] $($state:tt)*]
+///         $($input:tt)*
+///     ) => {
+///         $($return)*!{@scan_args[@__dmutil_version $version [$($return)*] $($state)*] $($input)*}
+///     };
+/// }
+/// fn main(){
+///     let x: i32 = eager!{unversioned!()};
+/// }
+/// ```
+///
+/// Versions of `eager!` from before the protocol was versioned call macros using `@eager`
+/// and a state without a version. This is still accepted, and the state is translated
+/// to the current version, such that such a call continues like any other:
+/// ```
+/// #[macro_use] extern crate dmutil;
+/// eager_macro_rules!{ $
+///     macro_rules! three{
+///         ()=>{3};
+///     }
+/// }
+/// fn main(){
+///     // The state an earlier 'eager!{10 - three!()}' would give
+///     let x: i32 = three!{@eager[[[] [] [- 10] []]]};
+///     assert_eq!(7, x);
+/// }
+/// ```
+///
+/// If an `eager!`-enabled macro is called inside `eager!` with input none of its rules accept,
+/// the error names the macro and shows the input it was given:
+/// ```compile_fail
+/// #[macro_use] extern crate dmutil;
+/// eager_macro_rules!{ $eager_1
+///     macro_rules! some_macro{
+///         ()=>{};
+///     }
+/// }
+/// fn main(){
+///     // error: eager!: no rules of 'some_macro !' expected the input: '1 2 3'
+///     eager!{some_macro!(1 2 3)};
+/// }
+/// ```
+///
+/// Likewise, a macro with the `only` option emits an error when used outside `eager!`:
+/// ```compile_fail
+/// #[macro_use] extern crate dmutil;
+/// eager_macro_rules!{ $eager_1
+///     #[eager(only)]
+///     macro_rules! some_macro{
+///         ()=>{};
+///     }
+/// }
+/// fn main(){
+///     // error: 'some_macro !' must be used inside 'eager!'
+///     some_macro!();
+/// }
+/// ```
 ///
 #[macro_export]
 macro_rules! eager_macro_rules{
 
 // Start by decoding the initial values
-	(
+	(	// The auxiliary variable is no longer needed, so its identifier is ignored
 		$dollar1:tt $id_1:ident
 		$(
 			$(#[$($metas:tt)*])*
-			macro_rules! $macro_name:ident {
-				$($rules:tt => $expansions:tt);* $(;)*
-			}
+			macro_rules! $macro_name:ident $rules:tt $(;)?
 		)+
 	)=>{
+		$crate::eager_macro_rules!{
+			$dollar1
+			$(
+				$(#[$($metas)*])*
+				macro_rules! $macro_name $rules
+			)+
+		}
+	};
+	(	// The rules may be in any type of block, e.g. 'macro_rules! some_macro( ... );'
+		$dollar1:tt
 		$(
-			eager_macro_rules_internal!{
-				@first[
-					$(#[$($metas)*])*
-					$macro_name $dollar1 $id_1
+			$(#[$($metas:tt)*])*
+			macro_rules! $macro_name:ident $rules:tt $(;)?
+		)+
+	)=>{
+		$(
+			$crate::eager_macro_rules_internal!{
+				@attributes[
+					[$macro_name $dollar1 __dmutil_state []]
+					[[] [] []]
 				]
-				$($rules => $expansions)*
+				$(#[$($metas)*])*
+				$rules
 			}
 		)+
 	};
 }
 
+///
+/// Checks at compile time that the given macros are [eager!](macro.eager.html)-enabled.
+///
+/// The macros are given by name or path, separated by commas. Each is asked whether it
+/// was declared using [`eager_macro_rules!`](macro.eager_macro_rules.html), which only
+/// such macros answer correctly. The question is a string saying that the macro is not
+/// `eager!`-enabled, so if none of the macro's rules accept it, the compiler's error that no rules
+/// expected it shows that message, and points at the macro. If a rule does accept it,
+/// the compiler instead reports that it expected the type
+/// `__macro_is_not_eager_enabled_so_wrap_it_in_lazy` and found something else.
+/// Such a macro must be wrapped in `lazy!` when used inside `eager!`.
+/// A macro can't find out whether another macro failed, so the message can't name the macro itself.
+///
+/// ```
+/// #[macro_use] extern crate dmutil;
+/// eager_macro_rules!{ $
+///     macro_rules! some_macro{
+///         ()=>{};
+///     }
+/// }
+///
+/// assert_eager_enabled!(some_macro, dmutil::reverse_tt);
+///
+/// fn main(){}
+/// ```
+/// ```compile_fail
+/// #[macro_use] extern crate dmutil;
+/// macro_rules! some_macro{
+///     ()=>{};
+/// }
+///
+/// // error: no rules expected `"assert_eager_enabled!: this macro is not eager!-enabled, ..."`
+/// assert_eager_enabled!(some_macro);
+///
+/// fn main(){}
+/// ```
+/// ```compile_fail
+/// #[macro_use] extern crate dmutil;
+/// macro_rules! catch_all{
+///     ($($input:tt)*)=>{};
+/// }
+///
+/// // error: mismatched types: expected `__macro_is_not_eager_enabled_so_wrap_it_in_lazy`, found `()`
+/// assert_eager_enabled!(catch_all);
+///
+/// fn main(){}
+/// ```
+///
+#[macro_export]
+macro_rules! assert_eager_enabled{
+	() => {};
+	($($paths:tt)+) => {
+		$crate::eager_macro_rules_internal!{
+			@assert_eager_enabled[]
+			$($paths)+
+		}
+	};
+}
+
 #[macro_export]
 #[doc(hidden)]
 macro_rules! eager_macro_rules_internal{
-// If there are no more rules, finish
-	(
-		@first[
-			$(#[$($metas:tt)*])*
-			$macro_name:ident $dollar1:tt $id_1:ident
-			$($prev_grammar:tt => $prev_expansion:tt)*
+// Check that macros are eager!-enabled
+	(	// At a comma, the path is done, so check the macro and continue with the rest
+		@assert_eager_enabled[$($path:tt)+]
+		, $($rest:tt)*
+	) => {
+		$crate::eager_macro_rules_internal!{@assert_eager_enabled[$($path)+]}
+		$crate::assert_eager_enabled!{$($rest)*}
+	};
+	(	// At the end of the input, so is the last path.
+		// The question is a message, such that a macro that doesn't accept it shows it in the error
+		@assert_eager_enabled[$($path:tt)+]
+	) => {
+		const _: $crate::__macro_is_not_eager_enabled_so_wrap_it_in_lazy = {
+			$($path)+!{"assert_eager_enabled!: this macro is not eager!-enabled, so wrap it in lazy! inside eager!"}
+		};
+	};
+	(	// Otherwise, the next token is part of the path
+		@assert_eager_enabled[$($path:tt)*]
+		$next:tt $($rest:tt)*
+	) => {
+		$crate::eager_macro_rules_internal!{
+			@assert_eager_enabled[$($path)* $next]
+			$($rest)*
+		}
+	};
+
+// Decode the attributes of the macro
+	(	// All options in the 'eager' attribute have been handled, so remove it
+		@attributes[$header:tt $options:tt]
+		#[eager()] $($rest:tt)*
+	) => {
+		$crate::eager_macro_rules_internal!{
+			@attributes[$header $options]
+			$($rest)*
+		}
+	};
+	(	// The macro must be given its input without it being scanned
+		@attributes[$header:tt [$lazy_args:tt $no_rescan:tt $only:tt]]
+		#[eager(lazy_args $(, $($options:tt)*)?)] $($rest:tt)*
+	) => {
+		$crate::eager_macro_rules_internal!{
+			@attributes[$header [[@lazy_args] $no_rescan $only]]
+			#[eager($($($options)*)?)] $($rest)*
+		}
+	};
+	(	// The expansion of the macro must not be scanned
+		@attributes[$header:tt [$lazy_args:tt $no_rescan:tt $only:tt]]
+		#[eager(no_rescan $(, $($options:tt)*)?)] $($rest:tt)*
+	) => {
+		$crate::eager_macro_rules_internal!{
+			@attributes[$header [$lazy_args [@no_rescan] $only]]
+			#[eager($($($options)*)?)] $($rest)*
+		}
+	};
+	(	// The macro must only be used inside 'eager!'
+		@attributes[$header:tt [$lazy_args:tt $no_rescan:tt $only:tt]]
+		#[eager(only $(, $($options:tt)*)?)] $($rest:tt)*
+	) => {
+		$crate::eager_macro_rules_internal!{
+			@attributes[$header [$lazy_args $no_rescan [@only]]]
+			#[eager($($($options)*)?)] $($rest)*
+		}
+	};
+	(	// Unknown option
+		@attributes[[$macro_name:ident $dollar1:tt $id_1:ident $metas:tt] $options:tt]
+		#[eager($($unknown:tt)*)] $($rest:tt)*
+	) => {
+		compile_error!{
+			concat!(
+				"eager_macro_rules!: unknown option in '#[eager(", stringify!($($unknown)*),
+				")]' on '", stringify!($macro_name), "'"
+			)
+		}
+	};
+	(	// Any other attribute is kept for the macro
+		@attributes[[$macro_name:ident $dollar1:tt $id_1:ident [$($metas:tt)*]] $options:tt]
+		#[$($meta:tt)*] $($rest:tt)*
+	) => {
+		$crate::eager_macro_rules_internal!{
+			@attributes[[$macro_name $dollar1 $id_1 [$($metas)* #[$($meta)*]]] $options]
+			$($rest)*
+		}
+	};
+	(	// When there are no more attributes, decode the rules.
+		// Each rule starts with the options of the macro (brace type)
+		@attributes[$header:tt $options:tt]
+		{$($rules:tt)*}
+	) => {
+		$crate::eager_macro_rules_internal!{
+			@rules[$header $options $options [][][]]
+			$($rules)*
+		}
+	};
+	(	// When there are no more attributes, decode the rules.
+		// Each rule starts with the options of the macro (parenthesis type)
+		@attributes[$header:tt $options:tt]
+		($($rules:tt)*)
+	) => {
+		$crate::eager_macro_rules_internal!{
+			@rules[$header $options $options [][][]]
+			$($rules)*
+		}
+	};
+	(	// When there are no more attributes, decode the rules.
+		// Each rule starts with the options of the macro (bracket type)
+		@attributes[$header:tt $options:tt]
+		[$($rules:tt)*]
+	) => {
+		$crate::eager_macro_rules_internal!{
+			@rules[$header $options $options [][][]]
+			$($rules)*
+		}
+	};
+
+// Decode the rules
+	(	// If there are no more rules and the macro must only be used inside 'eager!',
+		// finish with a rule that says so when called outside it
+		@rules[
+			[$macro_name:ident $dollar1:tt $id_1:ident $metas:tt]
+			[$lazy_args:tt $no_rescan:tt [@only]] $rule_options:tt $lazy:tt $scanned:tt $all:tt
 		]
 	) => {
-		eager_macro_rules_internal!{
+		$crate::eager_macro_rules_internal!{
 			@final[
-				$(#[$($metas)*])*
-				$macro_name$dollar1 $id_1
-				$($prev_grammar => $prev_expansion)*
+				[$macro_name $dollar1 $id_1 $metas] $lazy $scanned $all
+				[
+					{$dollar1($dollar1 input:tt)*} => {
+						compile_error!{
+							concat!("'", stringify!($macro_name!), "' must be used inside 'eager!'")
+						}
+					}
+				]
 			]
 		}
 	};
+	(	// If there are no more rules, finish
+		@rules[$header:tt $macro_options:tt $rule_options:tt $lazy:tt $scanned:tt $all:tt]
+	) => {
+		$crate::eager_macro_rules_internal!{
+			@final[$header $lazy $scanned $all []]
+		}
+	};
+	(	// Ignore the separator between rules
+		@rules[$($state:tt)*]
+		; $($rest:tt)*
+	) => {
+		$crate::eager_macro_rules_internal!{
+			@rules[$($state)*]
+			$($rest)*
+		}
+	};
+	(	// All options in the 'eager' attribute have been handled, so remove it
+		@rules[$header:tt $macro_options:tt $rule_options:tt $lazy:tt $scanned:tt $all:tt]
+		#[eager()] $($rest:tt)*
+	) => {
+		$crate::eager_macro_rules_internal!{
+			@rules[$header $macro_options $rule_options $lazy $scanned $all]
+			$($rest)*
+		}
+	};
+	(	// The rule must be given its input without it being scanned
+		@rules[$header:tt $macro_options:tt [$lazy_args:tt $no_rescan:tt $only:tt] $lazy:tt $scanned:tt $all:tt]
+		#[eager(lazy_args $(, $($options:tt)*)?)] $($rest:tt)*
+	) => {
+		$crate::eager_macro_rules_internal!{
+			@rules[$header $macro_options [[@lazy_args] $no_rescan $only] $lazy $scanned $all]
+			#[eager($($($options)*)?)] $($rest)*
+		}
+	};
+	(	// The expansion of the rule must not be scanned
+		@rules[$header:tt $macro_options:tt [$lazy_args:tt $no_rescan:tt $only:tt] $lazy:tt $scanned:tt $all:tt]
+		#[eager(no_rescan $(, $($options:tt)*)?)] $($rest:tt)*
+	) => {
+		$crate::eager_macro_rules_internal!{
+			@rules[$header $macro_options [$lazy_args [@no_rescan] $only] $lazy $scanned $all]
+			#[eager($($($options)*)?)] $($rest)*
+		}
+	};
+	(	// The rule must only be used inside 'eager!'
+		@rules[$header:tt $macro_options:tt [$lazy_args:tt $no_rescan:tt $only:tt] $lazy:tt $scanned:tt $all:tt]
+		#[eager(only $(, $($options:tt)*)?)] $($rest:tt)*
+	) => {
+		$crate::eager_macro_rules_internal!{
+			@rules[$header $macro_options [$lazy_args $no_rescan [@only]] $lazy $scanned $all]
+			#[eager($($($options)*)?)] $($rest)*
+		}
+	};
+	(	// Unknown option
+		@rules[[$macro_name:ident $dollar1:tt $id_1:ident $metas:tt] $($state:tt)*]
+		#[eager($($unknown:tt)*)] $($rest:tt)*
+	) => {
+		compile_error!{
+			concat!(
+				"eager_macro_rules!: unknown option in '#[eager(", stringify!($($unknown)*),
+				")]' on a rule of '", stringify!($macro_name), "'"
+			)
+		}
+	};
+	(	// A rule with '#[cfg(...)]' must only exist if the predicate holds.
+		// Since rules can't have attributes in 'macro_rules!', continue in two invocations,
+		// one with the rule where the predicate holds and one without it where it doesn't,
+		// such that only one of them is expanded
+		@rules[$($state:tt)*]
+		#[cfg($($predicate:tt)*)] $($rest:tt)*
+	) => {
+		#[cfg($($predicate)*)]
+		$crate::eager_macro_rules_internal!{
+			@rules[$($state)*]
+			$($rest)*
+		}
+		#[cfg(not($($predicate)*))]
+		$crate::eager_macro_rules_internal!{
+			@rules[$($state)*]
+			@skip_rule $($rest)*
+		}
+	};
+	(	// Likewise, a rule with '#[cfg_attr(...)]' only has the attributes if the predicate holds
+		@rules[$($state:tt)*]
+		#[cfg_attr($predicate:meta, $($attributes:tt)*)] $($rest:tt)*
+	) => {
+		#[cfg($predicate)]
+		$crate::eager_macro_rules_internal!{
+			@rules[$($state)*]
+			@cfg_attr[[] $($attributes)*] $($rest)*
+		}
+		#[cfg(not($predicate))]
+		$crate::eager_macro_rules_internal!{
+			@rules[$($state)*]
+			$($rest)*
+		}
+	};
+	(	// At a comma, the attribute is done
+		@rules[$($state:tt)*]
+		@cfg_attr[[$($attribute:tt)+] , $($attributes:tt)*] $($rest:tt)*
+	) => {
+		$crate::eager_macro_rules_internal!{
+			@rules[$($state)*]
+			#[$($attribute)+] @cfg_attr[[] $($attributes)*] $($rest)*
+		}
+	};
+	(	// At the end, so is the last attribute
+		@rules[$($state:tt)*]
+		@cfg_attr[[$($attribute:tt)+]] $($rest:tt)*
+	) => {
+		$crate::eager_macro_rules_internal!{
+			@rules[$($state)*]
+			#[$($attribute)+] $($rest)*
+		}
+	};
+	(	// Ignore a trailing comma
+		@rules[$($state:tt)*]
+		@cfg_attr[[]] $($rest:tt)*
+	) => {
+		$crate::eager_macro_rules_internal!{
+			@rules[$($state)*]
+			$($rest)*
+		}
+	};
+	(	// Otherwise, the next token is part of the attribute
+		@rules[$($state:tt)*]
+		@cfg_attr[[$($attribute:tt)*] $next:tt $($attributes:tt)*] $($rest:tt)*
+	) => {
+		$crate::eager_macro_rules_internal!{
+			@rules[$($state)*]
+			@cfg_attr[[$($attribute)* $next] $($attributes)*] $($rest)*
+		}
+	};
+	(	// Rules can't have any other attributes in 'macro_rules!', so they are an error
+		// instead of being ignored
+		@rules[[$macro_name:ident $dollar1:tt $id_1:ident $metas:tt] $($state:tt)*]
+		#[$($meta:tt)*] $($rest:tt)*
+	) => {
+		compile_error!{
+			concat!(
+				"eager_macro_rules!: a rule of '", stringify!($macro_name), "' was given '#[",
+				stringify!($($meta)*), "]', but rules can only be given '#[eager(...)]', ",
+				"'#[cfg(...)]' and '#[cfg_attr(...)]'"
+			)
+		}
+	};
+	(	// When skipping a rule, also skip the rest of its attributes
+		@rules[$($state:tt)*]
+		@skip_rule #[$($meta:tt)*] $($rest:tt)*
+	) => {
+		$crate::eager_macro_rules_internal!{
+			@rules[$($state)*]
+			@skip_rule $($rest)*
+		}
+	};
+	(
+		@rules[$($state:tt)*]
+		@skip_rule @cfg_attr $attributes:tt $($rest:tt)*
+	) => {
+		$crate::eager_macro_rules_internal!{
+			@rules[$($state)*]
+			@skip_rule $($rest)*
+		}
+	};
+	(	// Then skip the rule, and any options it had
+		@rules[$header:tt $macro_options:tt $rule_options:tt $lazy:tt $scanned:tt $all:tt]
+		@skip_rule $grammar:tt => $expansion:tt $($rest:tt)*
+	) => {
+		$crate::eager_macro_rules_internal!{
+			@rules[$header $macro_options $macro_options $lazy $scanned $all]
+			$($rest)*
+		}
+	};
 
 //Handle the 3 different block type before the '=>'
 	(
-		@first[
-			$(#[$($metas:tt)*])*
-			$macro_name:ident $dollar1:tt $id_1:ident
-			$($prev_grammar:tt => $prev_expansion:tt)*
-		]
+		@rules[$($state:tt)*]
 		{$($next_grammar:tt)*} $($rest:tt)+
 	) => {
-		eager_macro_rules_internal!{
-			@expansion[
-				$(#[$($metas)*])*
-				$macro_name$dollar1 $id_1
-				$($prev_grammar => $prev_expansion)*
-				[$($next_grammar)*]
-			]
+		$crate::eager_macro_rules_internal!{
+			@expansion[$($state)* {$($next_grammar)*}]
 			$($rest)+
 		}
 	};
 	(
-		@first[
-			$(#[$($metas:tt)*])*
-			$macro_name:ident $dollar1:tt $id_1:ident
-			$($prev_grammar:tt => $prev_expansion:tt)*
-		]
+		@rules[$($state:tt)*]
 		($($next_grammar:tt)*) $($rest:tt)+
 	) => {
-		eager_macro_rules_internal!{
-			@expansion[
-				$(#[$($metas)*])*
-				$macro_name$dollar1 $id_1
-				$($prev_grammar => $prev_expansion)*
-				[$($next_grammar)*]
-			]
+		$crate::eager_macro_rules_internal!{
+			@expansion[$($state)* {$($next_grammar)*}]
 			$($rest)+
 		}
 	};
 	(
-		@first[
-			$(#[$($metas:tt)*])*
-			$macro_name:ident $dollar1:tt $id_1:ident
-			$($prev_grammar:tt => $prev_expansion:tt)*
-		]
+		@rules[$($state:tt)*]
 		[$($next_grammar:tt)*] $($rest:tt)+
 	) => {
-		eager_macro_rules_internal!{
-			@expansion[
-				$(#[$($metas)*])*
-				$macro_name$dollar1 $id_1
-				$($prev_grammar => $prev_expansion)*
-				[$($next_grammar)*]
-			]
+		$crate::eager_macro_rules_internal!{
+			@expansion[$($state)* {$($next_grammar)*}]
 			$($rest)+
 		}
 	};
 	
 // Handle the 3 different block types after the '=>'
 	(
-		@expansion[
-			$(#[$($metas:tt)*])*
-			$macro_name:ident $dollar1:tt $id_1:ident
-			$({$($prev_grammar:tt)*} => $prev_expansion:tt)*
-			[$($next_grammar:tt)*]
-		]
+		@expansion[$($state:tt)*]
 		 => {$($next_expansion:tt)*} $($rest:tt)*
 	) => {
-		eager_macro_rules_internal!{
-			@first[
-				$(#[$($metas)*])*
-				$macro_name$dollar1 $id_1
-				$({$($prev_grammar)*}  => $prev_expansion)*
-				{$($next_grammar)*} => {$($next_expansion)*}
-			]
+		$crate::eager_macro_rules_internal!{
+			@push[$($state)* {$($next_expansion)*}]
 			$($rest)*
 		}
 	};
 	(
-		@expansion[
-			$(#[$($metas:tt)*])*
-			$macro_name:ident $dollar1:tt $id_1:ident
-			$({$($prev_grammar:tt)*} => $prev_expansion:tt)*
-			[$($next_grammar:tt)*]
-		]
+		@expansion[$($state:tt)*]
 		 => ($($next_expansion:tt)*) $($rest:tt)*
 	) => {
-		eager_macro_rules_internal!{
-			@first[
-				$(#[$($metas)*])*
-				$macro_name$dollar1 $id_1
-				$({$($prev_grammar)*}  => $prev_expansion)*
-				{$($next_grammar)*} => {$($next_expansion)*}
-			]
+		$crate::eager_macro_rules_internal!{
+			@push[$($state)* {$($next_expansion)*}]
 			$($rest)*
 		}
 	};
 	(
-		@expansion[
-			$(#[$($metas:tt)*])*
-			$macro_name:ident $dollar1:tt $id_1:ident
-			$({$($prev_grammar:tt)*} => $prev_expansion:tt)*
-			[$($next_grammar:tt)*]
-		]
+		@expansion[$($state:tt)*]
 		 => [$($next_expansion:tt)*] $($rest:tt)*
 	) => {
-		eager_macro_rules_internal!{
-			@first[
-				$(#[$($metas)*])*
-				$macro_name$dollar1 $id_1
-				$({$($prev_grammar)*}  => $prev_expansion)*
-				{$($next_grammar)*} => {$($next_expansion)*}
+		$crate::eager_macro_rules_internal!{
+			@push[$($state)* {$($next_expansion)*}]
+			$($rest)*
+		}
+	};
+
+// Add the rule to the rules of the macro
+	(	// Unless the rule must only be used inside 'eager!', add it to the pure rules,
+		// after which it is handled like one that must
+		@push[
+			$header:tt $macro_options:tt [$lazy_args:tt $no_rescan:tt []]
+			$lazy:tt $scanned:tt [$($all:tt)*]
+			$grammar:tt $expansion:tt
+		]
+		$($rest:tt)*
+	) => {
+		$crate::eager_macro_rules_internal!{
+			@push[
+				$header $macro_options [$lazy_args $no_rescan [@only]]
+				$lazy $scanned [$($all)* $grammar => $expansion]
+				$grammar $expansion
+			]
+			$($rest)*
+		}
+	};
+	(	// The rule must be given its input without it being scanned
+		@push[
+			$header:tt $macro_options:tt [[@lazy_args] $no_rescan:tt [@only]]
+			[$($lazy:tt)*] $scanned:tt $all:tt
+			$grammar:tt $expansion:tt
+		]
+		$($rest:tt)*
+	) => {
+		$crate::eager_macro_rules_internal!{
+			@rules[
+				$header $macro_options $macro_options
+				[$($lazy)* $no_rescan $grammar => $expansion] $scanned $all
+			]
+			$($rest)*
+		}
+	};
+	(	// The rule must be given its input after it has been scanned
+		@push[
+			$header:tt $macro_options:tt [[] $no_rescan:tt [@only]]
+			$lazy:tt [$($scanned:tt)*] $all:tt
+			$grammar:tt $expansion:tt
+		]
+		$($rest:tt)*
+	) => {
+		$crate::eager_macro_rules_internal!{
+			@rules[
+				$header $macro_options $macro_options
+				$lazy [$($scanned)* $no_rescan $grammar => $expansion] $all
 			]
 			$($rest)*
 		}
@@ -203,34 +779,161 @@ macro_rules! eager_macro_rules_internal{
 
 // Output
 	(	@final[
-			$(#[$($metas:tt)*])*
-			$macro_name:ident $dollar1:tt $id_1:ident
-			$({$($rules_grammar:tt)*} => {$($rules_expansion:tt)*})+
+			[$macro_name:ident $dollar1:tt $id_1:ident [$(#[$($metas:tt)*])*]]
+			[$([$($lazy_return:tt)*] {$($lazy_grammar:tt)*} => {$($lazy_expansion:tt)*})*]
+			[$([$($scanned_return:tt)*] {$($scanned_grammar:tt)*} => {$($scanned_expansion:tt)*})*]
+			[$({$($rules_grammar:tt)*} => {$($rules_expansion:tt)*})*]
+			[$({$($last_grammar:tt)*} => {$($last_expansion:tt)*})?]
 		]
 	)=>{
 		$(#[$($metas)*])*
 		macro_rules! $macro_name{
 			$(
-				// First the eager supporting version
+				// First the eager supporting versions of the rules that take their input
+				// before it has been scanned
 				{
-					@eager[$dollar1($dollar1 $id_1:tt)*]
-					$($rules_grammar)*
+					@__dmutil_eager_args[$dollar1($dollar1 $id_1:tt)*]
+					$($lazy_grammar)*
 				} => {
-					eager_internal!{
-						@from_macro[$dollar1($dollar1 $id_1)*]
-						$($rules_expansion)*
+					$crate::eager_internal!{
+						@from_macro[$($lazy_return)* $dollar1($dollar1 $id_1)*]
+						$($lazy_expansion)*
 					}
 				};
-			)+
+			)*
+			
+			// If none of them accepted the input and there is none, there is nothing to scan,
+			// so if the state is from this version of the protocol, call the other rules right away
+			// through the path in it, since this macro may not be in scope by name
+			{
+				@__dmutil_eager_args[
+					@__dmutil_version[1] $dollar1 return:tt
+					[$dollar1 lazy:tt $dollar1 modefix:tt $dollar1 prefix:tt $dollar1 postfix:tt $dollar1 block:tt [$dollar1($dollar1 path:tt)+]]
+					$dollar1($dollar1 $id_1:tt)*
+				]
+			} => {
+				$dollar1($dollar1 path)+!{
+					@__dmutil_eager[
+						@__dmutil_version[1] $dollar1 return
+						[$dollar1 lazy $dollar1 modefix $dollar1 prefix $dollar1 postfix $dollar1 block [$dollar1($dollar1 path)+]]
+						$dollar1($dollar1 $id_1)*
+					]
+				}
+			};
+			// Otherwise, decode the input right away if the state is
+			// from this version of the protocol, like 'eager!' does with '@scan_args'
+			{
+				@__dmutil_eager_args[@__dmutil_version[1] $dollar1 return:tt $dollar1($dollar1 $id_1:tt)*]
+				$dollar1($dollar1 input:tt)*
+			} => {
+				$crate::eager_internal!{
+					@check_expansion[[[][][][]] $dollar1($dollar1 $id_1)*]
+					$dollar1($dollar1 input)*
+				}
+			};
+			// Otherwise, ask for it to be scanned, such that the version is checked
+			{
+				@__dmutil_eager_args[$dollar1($dollar1 $id_1:tt)*]
+				$dollar1($dollar1 input:tt)*
+			} => {
+				$crate::eager_internal!{
+					@scan_args[$dollar1($dollar1 $id_1)*]
+					$dollar1($dollar1 input)*
+				}
+			};
+			
+			$(
+				// Then the eager supporting versions of the other rules
+				{
+					@__dmutil_eager[$dollar1($dollar1 $id_1:tt)*]
+					$($scanned_grammar)*
+				} => {
+					$crate::eager_internal!{
+						@from_macro[$($scanned_return)* $dollar1($dollar1 $id_1)*]
+						$($scanned_expansion)*
+					}
+				};
+			)*
+			
+			// If no eager version accepted the input, say which macro failed
+			// instead of the compiler complaining about '@__dmutil_eager'
+			{
+				@__dmutil_eager[$dollar1($dollar1 $id_1:tt)*]
+				$dollar1($dollar1 input:tt)*
+			} => {
+				compile_error!{
+					concat!(
+						"eager!: no rules of '", stringify!($macro_name!),
+						"' expected the input: '", stringify!($dollar1($dollar1 input)*), "'"
+					)
+				}
+			};
+			
+			// Used by 'eager!{@only[...] ...}' and 'eager!{@except[...] ...}'
+			// to find out whether this macro has the given name
+			{
+				@__dmutil_is[@__dmutil_version $dollar1 version:tt [$dollar1($dollar1 return:tt)*] $macro_name]
+				[$dollar1($dollar1 yes:tt)*] $dollar1 no:tt
+			} => {
+				$dollar1($dollar1 return)*!{
+					@from_is[@__dmutil_version $dollar1 version [$dollar1($dollar1 return)*]]
+					$dollar1($dollar1 yes)*
+				}
+			};
+			{
+				@__dmutil_is[@__dmutil_version $dollar1 version:tt [$dollar1($dollar1 return:tt)*] $dollar1 name:tt]
+				$dollar1 yes:tt [$dollar1($dollar1 no:tt)*]
+			} => {
+				$dollar1($dollar1 return)*!{
+					@from_is[@__dmutil_version $dollar1 version [$dollar1($dollar1 return)*]]
+					$dollar1($dollar1 no)*
+				}
+			};
+			
+			// Used by 'assert_eager_enabled!' to check that this macro is 'eager!'-enabled.
+			// Only this rule gives the value it expects
+			{"assert_eager_enabled!: this macro is not eager!-enabled, so wrap it in lazy! inside eager!"}
+				=> {$crate::__macro_is_not_eager_enabled_so_wrap_it_in_lazy};
 			
 			$(
 				// Then the pure version. We put the pure versions
-				// last such that if it contains a '$($all:tt)*' rule,
+				// after the eager versions such that if it contains a '$($all:tt)*' rule,
 				// the pure version will not catch an eager call.
 				{$($rules_grammar)*} => {$($rules_expansion)*};
-			)+
+			)*
+			
+			$(
+				// Lastly, accept the old '@eager' marker used by earlier versions of 'eager!',
+				// which have already checked the input. Their state is returned with '@from_eager',
+				// such that it is translated to this version. These come after the pure versions,
+				// such that rules accepting '@eager' are not affected
+				{
+					@eager[$dollar1($dollar1 $id_1:tt)*]
+					$($lazy_grammar)*
+				} => {
+					$crate::eager_internal!{
+						@from_eager[$($lazy_return)* $dollar1($dollar1 $id_1)*]
+						$($lazy_expansion)*
+					}
+				};
+			)*
+			$(
+				{
+					@eager[$dollar1($dollar1 $id_1:tt)*]
+					$($scanned_grammar)*
+				} => {
+					$crate::eager_internal!{
+						@from_eager[$($scanned_return)* $dollar1($dollar1 $id_1)*]
+						$($scanned_expansion)*
+					}
+				};
+			)*
+			
+			$(
+				// With '#[eager(only)]', the rule saying the macro must be used inside 'eager!'
+				// comes last, such that the old '@eager' marker is still accepted
+				{$($last_grammar)*} => {$($last_expansion)*};
+			)?
 		}
 	};
 }
-
-
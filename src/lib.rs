@@ -1,6 +1,15 @@
 //!
 //! A collection of macros to ease the creation of other macros.
 //!
+//! The macros can either be imported using `#[macro_use] extern crate dmutil;` or by path,
+//! e.g. `use dmutil::{eager, eager_macro_rules};`. Macros declared using `eager_macro_rules!`
+//! can likewise be used inside `eager!` in other crates without importing anything else from
+//! this crate.
+//!
+
+// The documentation indents its examples with tabs, like the code,
+// and does not indent the continuation lines of list items.
+#![allow(clippy::tabs_in_doc_comments, clippy::doc_lazy_continuation)]
 
 #[macro_use]
 mod eager_macro_rules;
@@ -11,3 +20,9 @@ mod eager;
 #[macro_use]
 mod lazy;
 
+
+/// The value `assert_eager_enabled!` expects an `eager!`-enabled macro to expand to when probed,
+/// named such that the compiler's error says what is wrong when a macro is not.
+#[doc(hidden)]
+#[allow(non_camel_case_types)]
+pub struct __macro_is_not_eager_enabled_so_wrap_it_in_lazy;
@@ -45,13 +45,18 @@ eager_macro_rules! {
 	///
 	///		// Beware that only the order of the token trees is reversed
 	///		// and not the trees themselves.
-	///		// therefore, the following expands to '(3-1) == 2'
+	///		// therefore, the following expands to '(3-1) == 2'.
+	///		// Use `deep_reverse_tt!` to also reverse the trees.
 	///		assert!(reverse_tt!({ == 2 }[(3-1)]));
 	///
 	///		assert!(reverse_tt!({2}|{>}|{1}));
 	///		assert!(reverse_tt!({4 > 3}));
 	///		assert!(reverse_tt!([< 1]{2 -}|[6 -]{5 -}));
 	/// 	assert!(reverse_tt!([][1 > 2]));
+	///
+	///		// Long lists are reversed several tokens at a time, expanding to '5 == 1 + 1 + 1 + 1 + 1'
+	///		assert!(reverse_tt!([1 + 1 + 1 + 1 + 1 == 5]));
+	///		assert!(reverse_tt!({== 5}[1 + 1 + 1 + 1 + 1]));
 	/// }
 	/// ```
 	///
@@ -61,80 +66,350 @@ eager_macro_rules! {
 		{
 			$($rest:tt)*
 		}=>{
-			reverse_tt_internal!{
+			$crate::reverse_tt_internal!{
 				$($rest)*
 			}
 		};
 	}
 	
+	///
+	/// [[eager!](macro.eager.html)] Reverses a list of token trees (tt), including the contents
+	/// of every group in it.
+	///
+	/// Unlike [`reverse_tt!`](macro.reverse_tt.html), the contents of each group, i.e. `(..)`,
+	/// `[..]` or `{..}`, are also reversed, and so on for the groups in them. The groups keep
+	/// their delimiters: `1 (2 3) [4 {5 6}] -> [{6 5} 4] (3 2) 1`. Unlike `reverse_tt!`,
+	/// the input is not wrapped in brackets, and there is no chaining.
+	///
+	/// ```
+	/// #[macro_use]
+	/// extern crate dmutil;
+	/// fn main(){
+	///
+	///		// Expands to '1 == ((5 - 3) - 1)'
+	///		assert!(deep_reverse_tt!((1 - (3 - 5)) == 1));
+	///
+	///		assert_eq!([3, 2, 1], deep_reverse_tt!{[1, 2, 3]});
+	///		assert_eq!(
+	///			[[4, 3], [2, 1]],
+	///			deep_reverse_tt!{[[1, 2], [3, 4]]}
+	///		);
+	///
+	///		// Expands to '{ let x = 1; x + 1 }'
+	///		assert_eq!(2, deep_reverse_tt!({1 + x ; 1 = x let}));
+	///
+	///		// Like `reverse_tt!`, it can be used in `eager!`
+	///		assert_eq!([2, 1], eager!{deep_reverse_tt!{[1, reverse_tt!{[2]}]}});
+	/// }
+	/// ```
+	///
 	#[macro_export]
-	#[doc(hidden)]
-	macro_rules! reverse_tt_internal{
-		{
-			$(@done{$($prev:tt)*})* [$($all:tt)*] $($rest:tt)*
-		}=>{
-			reverse_tt!{
-				$(@done{$($prev)*})*
-				|{} [$($all)*] $($rest)*
-			}
-		};
-		{
-			$(@done{$($prev:tt)*})* |[$($all:tt)*] $($rest:tt)*
-		}=>{
-			reverse_tt!{
-				$(@done{$($prev)*})*
-				|{} [$($all)*] $($rest)*
-			}
-		};
-		{
-			{$($all:tt)*} $($rest:tt)*
-		}=>{
-			reverse_tt!{ |{$($all)*} $($rest)*}
-		};
-		{
-			// Reverse
-			$(@done{$($prev:tt)*})*
-			|{$($reversed:tt)*} [$start:tt $($unreversed:tt)*] $($rest:tt)*
-		}=>{
-			reverse_tt!{
-				$(@done{$($prev)*})*
-				|{$start $($reversed)*} [$($unreversed)*] $($rest)*
-			}
-		};
-		{
-			// nothing to a reverse
-			$(@done{$($prev:tt)*})*
-			|{$($reversed:tt)*} [] $($rest:tt)*
-		}=>{
-			reverse_tt!{
-				$(@done{$($prev)*})*
-				|{$($reversed)*} $($rest)*
-			}
-		};
-		{
-			// Non-reverse merge
-			$(@done{$($prev:tt)*})*
-			|{$($reversed:tt)*} {$($no_r:tt)*} $($rest:tt)*
-		}=>{
-			reverse_tt!{
-				$(@done{$($prev)*})*
-				|{$($no_r)* $($reversed)*} $($rest)*
-			}
-		};
+	macro_rules! deep_reverse_tt{
 		{
-			// We done know the next '|{}' is done
-			$(@done{$($prev:tt)*})* |{$($done:tt)*} | $($rest:tt)*
+			$($rest:tt)*
 		}=>{
-			reverse_tt!{
-				$(@done{$($prev)*})* @done{$($done)*}
-				| $($rest)*
+			$crate::deep_reverse_tt_internal!{
+				[] [] $($rest)*
 			}
 		};
-		{
-			//All done
-			$(@done{$($done:tt)*})* |{$($last:tt)*}
-		}=>{
-			$($($done)*)* $($last)*
-		};
 	}
-}
\ No newline at end of file
+}
+
+#[macro_export]
+#[doc(hidden)]
+macro_rules! reverse_tt_internal{
+	{
+		// Called by 'eager!' on the expansion of 'reverse_tt!', whose input it has already scanned.
+		// Reversing in plain recursion, instead of returning to 'eager!' after each step,
+		// keeps the recursion depth the same as outside 'eager!'
+		@__dmutil_eager_args[$($state:tt)*] $($input:tt)*
+	}=>{
+		$crate::reverse_tt_internal!{@return[$($state)*] $($input)*}
+	};
+	{
+		// Used by 'eager!{@except[...] ...}' to find out whether this macro has the given name,
+		// which it never has, since it is not meant to be called directly
+		@__dmutil_is[@__dmutil_version $version:tt [$($return:tt)*] $name:tt]
+		$yes:tt [$($no:tt)*]
+	}=>{
+		$($return)*!{@from_is[@__dmutil_version $version [$($return)*]] $($no)*}
+	};
+	{
+		$(@return $return:tt)? $(@done{$($prev:tt)*})* [$($all:tt)*] $($rest:tt)*
+	}=>{
+		$crate::reverse_tt_internal!{
+			$(@return $return)? $(@done{$($prev)*})*
+			|{} [$($all)*] $($rest)*
+		}
+	};
+	{
+		$(@return $return:tt)? $(@done{$($prev:tt)*})* |[$($all:tt)*] $($rest:tt)*
+	}=>{
+		$crate::reverse_tt_internal!{
+			$(@return $return)? $(@done{$($prev)*})*
+			|{} [$($all)*] $($rest)*
+		}
+	};
+	{
+		$(@return $return:tt)? {$($all:tt)*} $($rest:tt)*
+	}=>{
+		$crate::reverse_tt_internal!{$(@return $return)? |{$($all)*} $($rest)*}
+	};
+	{
+		// Reverse eight tokens at once, such that the recursion depth
+		// only grows with an eighth of the tokens
+		$(@return $return:tt)? $(@done{$($prev:tt)*})*
+		|{$($reversed:tt)*} [$t1:tt $t2:tt $t3:tt $t4:tt $t5:tt $t6:tt $t7:tt $t8:tt $($unreversed:tt)*] $($rest:tt)*
+	}=>{
+		$crate::reverse_tt_internal!{
+			$(@return $return)? $(@done{$($prev)*})*
+			|{$t8 $t7 $t6 $t5 $t4 $t3 $t2 $t1 $($reversed)*} [$($unreversed)*] $($rest)*
+		}
+	};
+	{
+		// Reverse four tokens at once
+		$(@return $return:tt)? $(@done{$($prev:tt)*})*
+		|{$($reversed:tt)*} [$t1:tt $t2:tt $t3:tt $t4:tt $($unreversed:tt)*] $($rest:tt)*
+	}=>{
+		$crate::reverse_tt_internal!{
+			$(@return $return)? $(@done{$($prev)*})*
+			|{$t4 $t3 $t2 $t1 $($reversed)*} [$($unreversed)*] $($rest)*
+		}
+	};
+	{
+		// Reverse two tokens at once
+		$(@return $return:tt)? $(@done{$($prev:tt)*})*
+		|{$($reversed:tt)*} [$t1:tt $t2:tt $($unreversed:tt)*] $($rest:tt)*
+	}=>{
+		$crate::reverse_tt_internal!{
+			$(@return $return)? $(@done{$($prev)*})*
+			|{$t2 $t1 $($reversed)*} [$($unreversed)*] $($rest)*
+		}
+	};
+	{
+		// Reverse
+		$(@return $return:tt)? $(@done{$($prev:tt)*})*
+		|{$($reversed:tt)*} [$start:tt $($unreversed:tt)*] $($rest:tt)*
+	}=>{
+		$crate::reverse_tt_internal!{
+			$(@return $return)? $(@done{$($prev)*})*
+			|{$start $($reversed)*} [$($unreversed)*] $($rest)*
+		}
+	};
+	{
+		// nothing to a reverse
+		$(@return $return:tt)? $(@done{$($prev:tt)*})*
+		|{$($reversed:tt)*} [] $($rest:tt)*
+	}=>{
+		$crate::reverse_tt_internal!{
+			$(@return $return)? $(@done{$($prev)*})*
+			|{$($reversed)*} $($rest)*
+		}
+	};
+	{
+		// Non-reverse merge
+		$(@return $return:tt)? $(@done{$($prev:tt)*})*
+		|{$($reversed:tt)*} {$($no_r:tt)*} $($rest:tt)*
+	}=>{
+		$crate::reverse_tt_internal!{
+			$(@return $return)? $(@done{$($prev)*})*
+			|{$($no_r)* $($reversed)*} $($rest)*
+		}
+	};
+	{
+		// We done know the next '|{}' is done
+		$(@return $return:tt)? $(@done{$($prev:tt)*})* |{$($done:tt)*} | $($rest:tt)*
+	}=>{
+		$crate::reverse_tt_internal!{
+			$(@return $return)? $(@done{$($prev)*})* @done{$($done)*}
+			| $($rest)*
+		}
+	};
+	{
+		// All done, and called by 'eager!', so give it the result
+		@return[$($state:tt)*] $(@done{$($done:tt)*})* |{$($last:tt)*}
+	}=>{
+		$crate::eager_internal!{@from_macro[$($state)*] $($($done)*)* $($last)*}
+	};
+	{
+		//All done
+		$(@done{$($done:tt)*})* |{$($last:tt)*}
+	}=>{
+		$($($done)*)* $($last)*
+	};
+}
+
+#[macro_export]
+#[doc(hidden)]
+macro_rules! deep_reverse_tt_internal{
+	{
+		// Called by 'eager!' on the expansion of 'deep_reverse_tt!', like 'reverse_tt_internal!'
+		@__dmutil_eager_args[$($state:tt)*] $($input:tt)*
+	}=>{
+		$crate::deep_reverse_tt_internal!{@return[$($state)*] $($input)*}
+	};
+	{
+		// Used by 'eager!{@except[...] ...}', like 'reverse_tt_internal!'
+		@__dmutil_is[@__dmutil_version $version:tt [$($return:tt)*] $name:tt]
+		$yes:tt [$($no:tt)*]
+	}=>{
+		$($return)*!{@from_is[@__dmutil_version $version [$($return)*]] $($no)*}
+	};
+	{
+		// Reverse the contents of a parenthesis group in its own level
+		$(@return $return:tt)? [$($reversed:tt)*] $parent:tt ($($group:tt)*) $($rest:tt)*
+	}=>{
+		$crate::deep_reverse_tt_internal!{
+			$(@return $return)? [] [() [$($reversed)*] [$($rest)*] $parent] $($group)*
+		}
+	};
+	{
+		// Reverse the contents of a bracket group in its own level
+		$(@return $return:tt)? [$($reversed:tt)*] $parent:tt [$($group:tt)*] $($rest:tt)*
+	}=>{
+		$crate::deep_reverse_tt_internal!{
+			$(@return $return)? [] [[] [$($reversed)*] [$($rest)*] $parent] $($group)*
+		}
+	};
+	{
+		// Reverse the contents of a brace group in its own level
+		$(@return $return:tt)? [$($reversed:tt)*] $parent:tt {$($group:tt)*} $($rest:tt)*
+	}=>{
+		$crate::deep_reverse_tt_internal!{
+			$(@return $return)? [] [{} [$($reversed)*] [$($rest)*] $parent] $($group)*
+		}
+	};
+	{
+		// If the second token is a group, reverse the tokens before it at once
+		$(@return $return:tt)? [$($reversed:tt)*] $parent:tt $t1:tt ($($group:tt)*) $($rest:tt)*
+	}=>{
+		$crate::deep_reverse_tt_internal!{
+			$(@return $return)? [$t1 $($reversed)*] $parent ($($group)*) $($rest)*
+		}
+	};
+	{
+		// Same as above, for the other group types
+		$(@return $return:tt)? [$($reversed:tt)*] $parent:tt $t1:tt [$($group:tt)*] $($rest:tt)*
+	}=>{
+		$crate::deep_reverse_tt_internal!{
+			$(@return $return)? [$t1 $($reversed)*] $parent [$($group)*] $($rest)*
+		}
+	};
+	{
+		// Same as above, for the other group types
+		$(@return $return:tt)? [$($reversed:tt)*] $parent:tt $t1:tt {$($group:tt)*} $($rest:tt)*
+	}=>{
+		$crate::deep_reverse_tt_internal!{
+			$(@return $return)? [$t1 $($reversed)*] $parent {$($group)*} $($rest)*
+		}
+	};
+	{
+		// If the third token is a group, reverse the tokens before it at once
+		$(@return $return:tt)? [$($reversed:tt)*] $parent:tt $t1:tt $t2:tt ($($group:tt)*) $($rest:tt)*
+	}=>{
+		$crate::deep_reverse_tt_internal!{
+			$(@return $return)? [$t2 $t1 $($reversed)*] $parent ($($group)*) $($rest)*
+		}
+	};
+	{
+		// Same as above, for the other group types
+		$(@return $return:tt)? [$($reversed:tt)*] $parent:tt $t1:tt $t2:tt [$($group:tt)*] $($rest:tt)*
+	}=>{
+		$crate::deep_reverse_tt_internal!{
+			$(@return $return)? [$t2 $t1 $($reversed)*] $parent [$($group)*] $($rest)*
+		}
+	};
+	{
+		// Same as above, for the other group types
+		$(@return $return:tt)? [$($reversed:tt)*] $parent:tt $t1:tt $t2:tt {$($group:tt)*} $($rest:tt)*
+	}=>{
+		$crate::deep_reverse_tt_internal!{
+			$(@return $return)? [$t2 $t1 $($reversed)*] $parent {$($group)*} $($rest)*
+		}
+	};
+	{
+		// If the fourth token is a group, reverse the tokens before it at once
+		$(@return $return:tt)? [$($reversed:tt)*] $parent:tt $t1:tt $t2:tt $t3:tt ($($group:tt)*) $($rest:tt)*
+	}=>{
+		$crate::deep_reverse_tt_internal!{
+			$(@return $return)? [$t3 $t2 $t1 $($reversed)*] $parent ($($group)*) $($rest)*
+		}
+	};
+	{
+		// Same as above, for the other group types
+		$(@return $return:tt)? [$($reversed:tt)*] $parent:tt $t1:tt $t2:tt $t3:tt [$($group:tt)*] $($rest:tt)*
+	}=>{
+		$crate::deep_reverse_tt_internal!{
+			$(@return $return)? [$t3 $t2 $t1 $($reversed)*] $parent [$($group)*] $($rest)*
+		}
+	};
+	{
+		// Same as above, for the other group types
+		$(@return $return:tt)? [$($reversed:tt)*] $parent:tt $t1:tt $t2:tt $t3:tt {$($group:tt)*} $($rest:tt)*
+	}=>{
+		$crate::deep_reverse_tt_internal!{
+			$(@return $return)? [$t3 $t2 $t1 $($reversed)*] $parent {$($group)*} $($rest)*
+		}
+	};
+	{
+		// Otherwise, none of the next four tokens are groups, so reverse them at once
+		$(@return $return:tt)? [$($reversed:tt)*] $parent:tt $t1:tt $t2:tt $t3:tt $t4:tt $($rest:tt)*
+	}=>{
+		$crate::deep_reverse_tt_internal!{
+			$(@return $return)? [$t4 $t3 $t2 $t1 $($reversed)*] $parent $($rest)*
+		}
+	};
+	{
+		// Or two of them
+		$(@return $return:tt)? [$($reversed:tt)*] $parent:tt $t1:tt $t2:tt $($rest:tt)*
+	}=>{
+		$crate::deep_reverse_tt_internal!{
+			$(@return $return)? [$t2 $t1 $($reversed)*] $parent $($rest)*
+		}
+	};
+	{
+		// Then the last token
+		$(@return $return:tt)? [$($reversed:tt)*] $parent:tt $t1:tt $($rest:tt)*
+	}=>{
+		$crate::deep_reverse_tt_internal!{
+			$(@return $return)? [$t1 $($reversed)*] $parent $($rest)*
+		}
+	};
+	{
+		// The contents of the group are reversed, so put it in front of its parent's
+		// reversed tokens and continue with the tokens after it
+		$(@return $return:tt)? [$($reversed:tt)*] [() [$($parent_reversed:tt)*] [$($parent_rest:tt)*] $grandparent:tt]
+	}=>{
+		$crate::deep_reverse_tt_internal!{
+			$(@return $return)? [($($reversed)*) $($parent_reversed)*] $grandparent $($parent_rest)*
+		}
+	};
+	{
+		// Same as above, for the other group types
+		$(@return $return:tt)? [$($reversed:tt)*] [[] [$($parent_reversed:tt)*] [$($parent_rest:tt)*] $grandparent:tt]
+	}=>{
+		$crate::deep_reverse_tt_internal!{
+			$(@return $return)? [[$($reversed)*] $($parent_reversed)*] $grandparent $($parent_rest)*
+		}
+	};
+	{
+		// Same as above, for the other group types
+		$(@return $return:tt)? [$($reversed:tt)*] [{} [$($parent_reversed:tt)*] [$($parent_rest:tt)*] $grandparent:tt]
+	}=>{
+		$crate::deep_reverse_tt_internal!{
+			$(@return $return)? [{$($reversed)*} $($parent_reversed)*] $grandparent $($parent_rest)*
+		}
+	};
+	{
+		// All done, and called by 'eager!', so give it the result
+		@return[$($state:tt)*] [$($reversed:tt)*] []
+	}=>{
+		$crate::eager_internal!{@from_macro[$($state)*] $($reversed)*}
+	};
+	{
+		// All done
+		[$($reversed:tt)*] []
+	}=>{
+		$($reversed)*
+	};
+}
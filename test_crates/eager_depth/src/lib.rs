@@ -0,0 +1,356 @@
+//!
+//! Pins the recursion limit that `eager!` needs to expand some reference inputs.
+//!
+//! Each input is given with the lowest recursion limit it can be expanded with, and again
+//! with one less, which must fail. If a change to `eager!` makes an input need a higher limit,
+//! the first fails, and if it makes it need a lower one, the second fails, such that
+//! the new limit can be pinned. For reference, the default limit of the compiler is 128.
+//!
+//! # A struct and an impl using `eager!`-enabled macros
+//!
+//! ```
+//! #![recursion_limit="80"]
+//! #[macro_use] extern crate dmutil;
+//! fn main(){
+//!     eager_macro_rules!{ $
+//!         macro_rules! field_type{
+//!             ()=>{u32};
+//!         }
+//!     }
+//!     eager!{
+//!         #[derive(Debug, Clone, PartialEq)]
+//!         pub struct Point{
+//!             pub x: field_type!(),
+//!             pub y: field_type!(),
+//!             pub z: field_type!(),
+//!             pub name: &'static str,
+//!             pub tags: Vec<(u8, &'static str)>,
+//!         }
+//!         impl Point{
+//!             pub fn new(x: field_type!(), y: field_type!(), z: field_type!()) -> Self {
+//!                 Point{ x: x, y: y, z: z, name: "point", tags: Vec::new() }
+//!             }
+//!             pub fn sum(&self) -> field_type!() {
+//!                 self.x + self.y + self.z
+//!             }
+//!             pub fn is_origin(&self) -> bool {
+//!                 self.x == 0 && self.y == 0 && self.z == 0 && !self.tags.is_empty()
+//!             }
+//!         }
+//!     }
+//! }
+//! ```
+//! ```compile_fail
+//! #![recursion_limit="79"]
+//! #[macro_use] extern crate dmutil;
+//! fn main(){
+//!     eager_macro_rules!{ $
+//!         macro_rules! field_type{
+//!             ()=>{u32};
+//!         }
+//!     }
+//!     eager!{
+//!         #[derive(Debug, Clone, PartialEq)]
+//!         pub struct Point{
+//!             pub x: field_type!(),
+//!             pub y: field_type!(),
+//!             pub z: field_type!(),
+//!             pub name: &'static str,
+//!             pub tags: Vec<(u8, &'static str)>,
+//!         }
+//!         impl Point{
+//!             pub fn new(x: field_type!(), y: field_type!(), z: field_type!()) -> Self {
+//!                 Point{ x: x, y: y, z: z, name: "point", tags: Vec::new() }
+//!             }
+//!             pub fn sum(&self) -> field_type!() {
+//!                 self.x + self.y + self.z
+//!             }
+//!             pub fn is_origin(&self) -> bool {
+//!                 self.x == 0 && self.y == 0 && self.z == 0 && !self.tags.is_empty()
+//!             }
+//!         }
+//!     }
+//! }
+//! ```
+//!
+//! # Ten functions calling `eager!`-enabled macros, in one `eager!`
+//!
+//! ```
+//! #![recursion_limit="51"]
+//! #[macro_use] extern crate dmutil;
+//! fn main(){
+//!     eager_macro_rules!{ $
+//!         macro_rules! one{
+//!             ()=>{1};
+//!         }
+//!     }
+//!     eager!{
+//!         fn f0() -> u32 { one!() + one!() + one!() }
+//!         fn f1() -> u32 { one!() + one!() + one!() }
+//!         fn f2() -> u32 { one!() + one!() + one!() }
+//!         fn f3() -> u32 { one!() + one!() + one!() }
+//!         fn f4() -> u32 { one!() + one!() + one!() }
+//!         fn f5() -> u32 { one!() + one!() + one!() }
+//!         fn f6() -> u32 { one!() + one!() + one!() }
+//!         fn f7() -> u32 { one!() + one!() + one!() }
+//!         fn f8() -> u32 { one!() + one!() + one!() }
+//!         fn f9() -> u32 { one!() + one!() + one!() }
+//!     }
+//! }
+//! ```
+//! ```compile_fail
+//! #![recursion_limit="50"]
+//! #[macro_use] extern crate dmutil;
+//! fn main(){
+//!     eager_macro_rules!{ $
+//!         macro_rules! one{
+//!             ()=>{1};
+//!         }
+//!     }
+//!     eager!{
+//!         fn f0() -> u32 { one!() + one!() + one!() }
+//!         fn f1() -> u32 { one!() + one!() + one!() }
+//!         fn f2() -> u32 { one!() + one!() + one!() }
+//!         fn f3() -> u32 { one!() + one!() + one!() }
+//!         fn f4() -> u32 { one!() + one!() + one!() }
+//!         fn f5() -> u32 { one!() + one!() + one!() }
+//!         fn f6() -> u32 { one!() + one!() + one!() }
+//!         fn f7() -> u32 { one!() + one!() + one!() }
+//!         fn f8() -> u32 { one!() + one!() + one!() }
+//!         fn f9() -> u32 { one!() + one!() + one!() }
+//!     }
+//! }
+//! ```
+//!
+//! # Forty functions calling `eager!`-enabled macros, in one `eager!`
+//!
+//! ```
+//! #![recursion_limit="111"]
+//! #[macro_use] extern crate dmutil;
+//! fn main(){
+//!     eager_macro_rules!{ $
+//!         macro_rules! one{
+//!             ()=>{1};
+//!         }
+//!     }
+//!     eager!{
+//!         fn f0() -> u32 { one!() + one!() + one!() }
+//!         fn f1() -> u32 { one!() + one!() + one!() }
+//!         fn f2() -> u32 { one!() + one!() + one!() }
+//!         fn f3() -> u32 { one!() + one!() + one!() }
+//!         fn f4() -> u32 { one!() + one!() + one!() }
+//!         fn f5() -> u32 { one!() + one!() + one!() }
+//!         fn f6() -> u32 { one!() + one!() + one!() }
+//!         fn f7() -> u32 { one!() + one!() + one!() }
+//!         fn f8() -> u32 { one!() + one!() + one!() }
+//!         fn f9() -> u32 { one!() + one!() + one!() }
+//!         fn f10() -> u32 { one!() + one!() + one!() }
+//!         fn f11() -> u32 { one!() + one!() + one!() }
+//!         fn f12() -> u32 { one!() + one!() + one!() }
+//!         fn f13() -> u32 { one!() + one!() + one!() }
+//!         fn f14() -> u32 { one!() + one!() + one!() }
+//!         fn f15() -> u32 { one!() + one!() + one!() }
+//!         fn f16() -> u32 { one!() + one!() + one!() }
+//!         fn f17() -> u32 { one!() + one!() + one!() }
+//!         fn f18() -> u32 { one!() + one!() + one!() }
+//!         fn f19() -> u32 { one!() + one!() + one!() }
+//!         fn f20() -> u32 { one!() + one!() + one!() }
+//!         fn f21() -> u32 { one!() + one!() + one!() }
+//!         fn f22() -> u32 { one!() + one!() + one!() }
+//!         fn f23() -> u32 { one!() + one!() + one!() }
+//!         fn f24() -> u32 { one!() + one!() + one!() }
+//!         fn f25() -> u32 { one!() + one!() + one!() }
+//!         fn f26() -> u32 { one!() + one!() + one!() }
+//!         fn f27() -> u32 { one!() + one!() + one!() }
+//!         fn f28() -> u32 { one!() + one!() + one!() }
+//!         fn f29() -> u32 { one!() + one!() + one!() }
+//!         fn f30() -> u32 { one!() + one!() + one!() }
+//!         fn f31() -> u32 { one!() + one!() + one!() }
+//!         fn f32() -> u32 { one!() + one!() + one!() }
+//!         fn f33() -> u32 { one!() + one!() + one!() }
+//!         fn f34() -> u32 { one!() + one!() + one!() }
+//!         fn f35() -> u32 { one!() + one!() + one!() }
+//!         fn f36() -> u32 { one!() + one!() + one!() }
+//!         fn f37() -> u32 { one!() + one!() + one!() }
+//!         fn f38() -> u32 { one!() + one!() + one!() }
+//!         fn f39() -> u32 { one!() + one!() + one!() }
+//!     }
+//! }
+//! ```
+//! ```compile_fail
+//! #![recursion_limit="110"]
+//! #[macro_use] extern crate dmutil;
+//! fn main(){
+//!     eager_macro_rules!{ $
+//!         macro_rules! one{
+//!             ()=>{1};
+//!         }
+//!     }
+//!     eager!{
+//!         fn f0() -> u32 { one!() + one!() + one!() }
+//!         fn f1() -> u32 { one!() + one!() + one!() }
+//!         fn f2() -> u32 { one!() + one!() + one!() }
+//!         fn f3() -> u32 { one!() + one!() + one!() }
+//!         fn f4() -> u32 { one!() + one!() + one!() }
+//!         fn f5() -> u32 { one!() + one!() + one!() }
+//!         fn f6() -> u32 { one!() + one!() + one!() }
+//!         fn f7() -> u32 { one!() + one!() + one!() }
+//!         fn f8() -> u32 { one!() + one!() + one!() }
+//!         fn f9() -> u32 { one!() + one!() + one!() }
+//!         fn f10() -> u32 { one!() + one!() + one!() }
+//!         fn f11() -> u32 { one!() + one!() + one!() }
+//!         fn f12() -> u32 { one!() + one!() + one!() }
+//!         fn f13() -> u32 { one!() + one!() + one!() }
+//!         fn f14() -> u32 { one!() + one!() + one!() }
+//!         fn f15() -> u32 { one!() + one!() + one!() }
+//!         fn f16() -> u32 { one!() + one!() + one!() }
+//!         fn f17() -> u32 { one!() + one!() + one!() }
+//!         fn f18() -> u32 { one!() + one!() + one!() }
+//!         fn f19() -> u32 { one!() + one!() + one!() }
+//!         fn f20() -> u32 { one!() + one!() + one!() }
+//!         fn f21() -> u32 { one!() + one!() + one!() }
+//!         fn f22() -> u32 { one!() + one!() + one!() }
+//!         fn f23() -> u32 { one!() + one!() + one!() }
+//!         fn f24() -> u32 { one!() + one!() + one!() }
+//!         fn f25() -> u32 { one!() + one!() + one!() }
+//!         fn f26() -> u32 { one!() + one!() + one!() }
+//!         fn f27() -> u32 { one!() + one!() + one!() }
+//!         fn f28() -> u32 { one!() + one!() + one!() }
+//!         fn f29() -> u32 { one!() + one!() + one!() }
+//!         fn f30() -> u32 { one!() + one!() + one!() }
+//!         fn f31() -> u32 { one!() + one!() + one!() }
+//!         fn f32() -> u32 { one!() + one!() + one!() }
+//!         fn f33() -> u32 { one!() + one!() + one!() }
+//!         fn f34() -> u32 { one!() + one!() + one!() }
+//!         fn f35() -> u32 { one!() + one!() + one!() }
+//!         fn f36() -> u32 { one!() + one!() + one!() }
+//!         fn f37() -> u32 { one!() + one!() + one!() }
+//!         fn f38() -> u32 { one!() + one!() + one!() }
+//!         fn f39() -> u32 { one!() + one!() + one!() }
+//!     }
+//! }
+//! ```
+//!
+//! # A struct with 50 fields, i.e. around 200 tokens in one block
+//!
+//! ```
+//! #![recursion_limit="34"]
+//! #[macro_use] extern crate dmutil;
+//! fn main(){
+//!     eager!{
+//!         pub struct Fields{
+//!         f0: u32, f1: u32, f2: u32, f3: u32, f4: u32,
+//!         f5: u32, f6: u32, f7: u32, f8: u32, f9: u32,
+//!         f10: u32, f11: u32, f12: u32, f13: u32, f14: u32,
+//!         f15: u32, f16: u32, f17: u32, f18: u32, f19: u32,
+//!         f20: u32, f21: u32, f22: u32, f23: u32, f24: u32,
+//!         f25: u32, f26: u32, f27: u32, f28: u32, f29: u32,
+//!         f30: u32, f31: u32, f32: u32, f33: u32, f34: u32,
+//!         f35: u32, f36: u32, f37: u32, f38: u32, f39: u32,
+//!         f40: u32, f41: u32, f42: u32, f43: u32, f44: u32,
+//!         f45: u32, f46: u32, f47: u32, f48: u32, f49: u32,
+//!         }
+//!     }
+//! }
+//! ```
+//! ```compile_fail
+//! #![recursion_limit="33"]
+//! #[macro_use] extern crate dmutil;
+//! fn main(){
+//!     eager!{
+//!         pub struct Fields{
+//!         f0: u32, f1: u32, f2: u32, f3: u32, f4: u32,
+//!         f5: u32, f6: u32, f7: u32, f8: u32, f9: u32,
+//!         f10: u32, f11: u32, f12: u32, f13: u32, f14: u32,
+//!         f15: u32, f16: u32, f17: u32, f18: u32, f19: u32,
+//!         f20: u32, f21: u32, f22: u32, f23: u32, f24: u32,
+//!         f25: u32, f26: u32, f27: u32, f28: u32, f29: u32,
+//!         f30: u32, f31: u32, f32: u32, f33: u32, f34: u32,
+//!         f35: u32, f36: u32, f37: u32, f38: u32, f39: u32,
+//!         f40: u32, f41: u32, f42: u32, f43: u32, f44: u32,
+//!         f45: u32, f46: u32, f47: u32, f48: u32, f49: u32,
+//!         }
+//!     }
+//! }
+//! ```
+//!
+//! # An expression of 81 tokens, not in a block
+//!
+//! ```
+//! #![recursion_limit="14"]
+//! #[macro_use] extern crate dmutil;
+//! fn main(){
+//!     const SUM: u32 = eager!{
+//!         0 + 1 + 1 + 1 + 1 + 1 + 1 + 1 + 1 + 1 + 1 + 1 + 1 + 1 + 1 + 1 + 1 + 1 + 1 + 1 + 1
+//!         + 1 + 1 + 1 + 1 + 1 + 1 + 1 + 1 + 1 + 1 + 1 + 1 + 1 + 1 + 1 + 1 + 1 + 1 + 1 + 1
+//!     };
+//! }
+//! ```
+//! ```compile_fail
+//! #![recursion_limit="13"]
+//! #[macro_use] extern crate dmutil;
+//! fn main(){
+//!     const SUM: u32 = eager!{
+//!         0 + 1 + 1 + 1 + 1 + 1 + 1 + 1 + 1 + 1 + 1 + 1 + 1 + 1 + 1 + 1 + 1 + 1 + 1 + 1 + 1
+//!         + 1 + 1 + 1 + 1 + 1 + 1 + 1 + 1 + 1 + 1 + 1 + 1 + 1 + 1 + 1 + 1 + 1 + 1 + 1 + 1
+//!     };
+//! }
+//! ```
+//!
+//! # `reverse_tt!` of 65 tokens, in `eager!`
+//!
+//! ```
+//! #![recursion_limit="47"]
+//! #[macro_use] extern crate dmutil;
+//! fn main(){
+//!     const SUM: u32 = eager!{
+//!         reverse_tt!{[0 + 1 + 1 + 1 + 1 + 1 + 1 + 1 + 1 + 1 + 1 + 1 + 1 + 1 + 1 + 1 + 1
+//!             + 1 + 1 + 1 + 1 + 1 + 1 + 1 + 1 + 1 + 1 + 1 + 1 + 1 + 1 + 1 + 1]}
+//!     };
+//! }
+//! ```
+//! ```compile_fail
+//! #![recursion_limit="46"]
+//! #[macro_use] extern crate dmutil;
+//! fn main(){
+//!     const SUM: u32 = eager!{
+//!         reverse_tt!{[0 + 1 + 1 + 1 + 1 + 1 + 1 + 1 + 1 + 1 + 1 + 1 + 1 + 1 + 1 + 1 + 1
+//!             + 1 + 1 + 1 + 1 + 1 + 1 + 1 + 1 + 1 + 1 + 1 + 1 + 1 + 1 + 1 + 1]}
+//!     };
+//! }
+//! ```
+//!
+//! # Macros of another crate, in `eager!`
+//!
+//! ```
+//! #![recursion_limit="20"]
+//! #[macro_use] extern crate dmutil;
+//! fn main(){
+//!     const SUM: u32 = eager!{eager_lib::add!(eager_lib::two_and_three!())};
+//! }
+//! ```
+//! ```compile_fail
+//! #![recursion_limit="19"]
+//! #[macro_use] extern crate dmutil;
+//! fn main(){
+//!     const SUM: u32 = eager!{eager_lib::add!(eager_lib::two_and_three!())};
+//! }
+//! ```
+//!
+//! # A macro of another crate expanding to its own `$crate::eager!`, in `eager!`
+//!
+//! ```
+//! #![recursion_limit="37"]
+//! #[macro_use] extern crate dmutil;
+//! fn main(){
+//!     const SUM: u32 = eager!{eager_lib::add!(eager_lib::add_two_and_three!(), 5)};
+//! }
+//! ```
+//! ```compile_fail
+//! #![recursion_limit="36"]
+//! #[macro_use] extern crate dmutil;
+//! fn main(){
+//!     const SUM: u32 = eager!{eager_lib::add!(eager_lib::add_two_and_three!(), 5)};
+//! }
+//! ```
+//!
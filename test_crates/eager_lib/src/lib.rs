@@ -0,0 +1,137 @@
+//!
+//! Exports `eager!`-enabled macros, such that `eager_user` can test using them
+//! in another crate. Only path-based imports are used.
+//!
+
+use dmutil::eager_macro_rules;
+
+#[doc(hidden)]
+pub use dmutil::eager;
+
+eager_macro_rules!{ $eager_1
+	#[macro_export]
+	macro_rules! add{
+		($e1:expr, $e2:expr)=> {$e1 + $e2}
+	}
+	
+	#[macro_export]
+	macro_rules! two_and_three{
+		()=>{2,3}
+	}
+	
+	#[macro_export]
+	macro_rules! id{
+		()=> {SomeStruct}
+	}
+	
+	/// Calls the other macros of this crate through `$crate`, such that they
+	/// don't have to be imported where this macro is used.
+	#[macro_export]
+	macro_rules! add_two_and_three{
+		()=> {$crate::eager!{$crate::add!($crate::two_and_three!())}}
+	}
+}
+
+#[cfg(test)]
+mod helpers{
+	use dmutil::eager_macro_rules;
+	
+	eager_macro_rules!{ $eager_1
+		macro_rules! double{
+			($e:expr)=> {2 * $e}
+		}
+		
+		/// Has the same name as `dmutil::lazy!`, but isn't it
+		macro_rules! lazy{
+			()=> {7}
+		}
+		
+		/// Reachable as `write!`, the same name as `std::write!`, but isn't it
+		macro_rules! triple{
+			($e:expr)=> {3 * $e}
+		}
+	}
+	// Makes the macros reachable through paths
+	#[allow(clippy::single_component_path_imports)]
+	pub(crate) use double;
+	#[allow(clippy::single_component_path_imports)]
+	pub(crate) use lazy;
+	pub(crate) use triple as write;
+}
+
+#[cfg(test)]
+mod tests{
+	use dmutil::{eager, eager_macro_rules};
+	
+	eager_macro_rules!{ $eager_1
+		macro_rules! five{
+			()=> {5}
+		}
+	}
+	// Makes the macro reachable through paths
+	#[allow(clippy::single_component_path_imports)]
+	pub(crate) use five;
+	
+	/// Has the same name as the `core` crate, but isn't it
+	mod core{
+		use dmutil::eager_macro_rules;
+		
+		eager_macro_rules!{ $eager_1
+			macro_rules! one{
+				()=> {1}
+			}
+		}
+		// Makes the macro reachable through paths
+		#[allow(clippy::single_component_path_imports)]
+		pub(crate) use one;
+	}
+	
+	mod inner{
+		use dmutil::eager;
+		
+		#[test]
+		fn test_super_path(){
+			assert_eq!(10, eager!{crate::helpers::double!(super::five!())});
+		}
+	}
+	
+	#[test]
+	fn test_self_path(){
+		assert_eq!(5, eager!{self::five!()});
+	}
+	
+	#[test]
+	fn test_crate_path(){
+		assert_eq!(10, eager!{crate::helpers::double!(five!())});
+	}
+	
+	#[test]
+	fn test_other_macro_named_lazy(){
+		assert_eq!(7, eager!{crate::helpers::lazy!()});
+	}
+	
+	#[test]
+	fn test_other_macro_named_like_std(){
+		use std::fmt::Write;
+		
+		assert_eq!(15, eager!{crate::helpers::write!(five!())});
+		
+		let mut s = String::new();
+		eager!(write!(s, "{}", crate::helpers::write!(five!()))).unwrap();
+		assert_eq!("15", s);
+	}
+	
+	#[test]
+	fn test_local_module_named_core(){
+		// 'core::one!()' is taken to be a standard library macro, so the compiler expands it
+		assert_eq!(2, eager!{core::one!() + self::core::one!()});
+		assert_eq!(5, eager!{::core::convert::identity(five!())});
+	}
+	
+	#[test]
+	fn test_nested_paths(){
+		assert_eq!(20, eager!{
+			crate::helpers::double!(self::super::helpers::double!(crate::tests::five!()))
+		});
+	}
+}
@@ -0,0 +1,87 @@
+//!
+//! Tests that `eager!`-enabled macros exported by `eager_lib` can be used
+//! inside `eager!` using only path-based imports.
+//!
+#![allow(dead_code)]
+
+use dmutil::eager;
+use eager_lib::id;
+
+dmutil::assert_eager_enabled!(eager_lib::add, ::eager_lib::two_and_three, id);
+
+eager!{
+	struct id!(){
+		v: u32
+	}
+}
+
+#[cfg(test)]
+mod tests{
+	use dmutil::{eager, reverse_tt};
+	use eager_lib::{add, two_and_three};
+	use super::SomeStruct;
+	
+	macro_rules! lazy_macro{
+		() => {1};
+	}
+	
+	#[test]
+	fn test_imported_eager_macros(){
+		assert_eq!(5, eager!{add!(two_and_three!())});
+	}
+	
+	#[test]
+	fn test_imported_macro_in_identifier_position(){
+		assert_eq!(4, SomeStruct{v: 4}.v);
+	}
+	
+	#[test]
+	fn test_lazy_without_importing_it_in_eager(){
+		assert_eq!(6, eager!{add!(lazy!{lazy_macro!()}, 5)});
+	}
+	
+	#[test]
+	fn test_lazy_by_path(){
+		assert_eq!(1, dmutil::lazy!{lazy_macro!()});
+	}
+	
+	#[test]
+	fn test_crate_paths(){
+		assert_eq!(5, eager!{eager_lib::add!(eager_lib::two_and_three!())});
+		assert_eq!(5, eager!{::eager_lib::add!(::eager_lib::two_and_three!())});
+	}
+	
+	#[test]
+	fn test_only_by_path(){
+		assert_eq!(5, eager!{@only[eager_lib::add, two_and_three] eager_lib::add!(two_and_three!())});
+		assert_eq!(6, eager!{@except[::eager_lib::add] add!(lazy_macro!(), 5)});
+	}
+	
+	#[test]
+	fn test_into_by_path(){
+		assert_eq!(5, eager!{@into[eager_lib::add!] two_and_three!()});
+		assert_eq!(1, eager!{@into[dmutil::lazy!] lazy!{lazy_macro!{}}});
+	}
+	
+	#[test]
+	fn test_dollar_crate_paths(){
+		assert_eq!(5, eager_lib::add_two_and_three!());
+		assert_eq!(10, eager!{add!(eager_lib::add_two_and_three!(), 5)});
+	}
+	
+	#[test]
+	fn test_eager_and_lazy_by_path(){
+		assert_eq!(6, eager!{add!(dmutil::lazy!{lazy_macro!()}, dmutil::eager!{5})});
+		assert_eq!(6, dmutil::eager!{add!(::dmutil::lazy!(lazy_macro!()), ::dmutil::eager!(5))});
+	}
+	
+	#[test]
+	fn test_reverse_tt_in_eager(){
+		assert_eq!(3, eager!{add!(reverse_tt!{[1, 2]})});
+	}
+	
+	#[test]
+	fn test_reverse_tt_by_path(){
+		assert_eq!(3, reverse_tt!([1 - 4]));
+	}
+}
@@ -398,3 +398,641 @@ mod pare_test_recursive_eagers{
 }
 
 
+
+// Same tests as above, but with the '[]' block type
+mod bracket_test_prefix{
+	/*
+	Tests that input can be followed by a macro call
+	*/
+	eager_macro_rules!{$eager_1
+		macro_rules! test_macro{
+			(!! ) =>{
+				eager!{
+					const N: [i32; 3] = test_macro![1];
+				}
+			};
+			( 1 ) =>{
+				[5 test_macro![2]]
+			};
+			( 2	) =>{
+				, 1 test_macro![3]
+			};
+			( 3 ) =>{
+				, [5][0]
+			};
+		}
+	}
+	test_macro!(!!);
+	#[test]
+	fn test(){
+		assert_eq!([5, 1, 5], N);
+	}
+}
+mod bracket_test_postfix{
+	/*
+	Tests that a macro call can be followed by a macro call
+	*/
+	eager_macro_rules!{$eager_1
+		macro_rules! test_macro{
+			(!! ) =>{
+				eager!{
+					const N: [i32; 3] = test_macro![1];
+				}
+			};
+			( 1 ) =>{
+				[test_macro![2] 5]
+			};
+			( 2 ) =>{
+				test_macro![3] 1,
+			};
+			( 3 ) =>{
+				[5][0],
+			};
+		}
+	}
+	test_macro!(!!);
+	#[test]
+	fn test(){
+		assert_eq!([5, 1, 5], N);
+	}
+}
+mod bracket_test_multiple_calls{
+	/*
+	Tests that multliple macro calls can be done in serial
+	*/
+	eager_macro_rules!{$eager_1
+		macro_rules! test_macro{
+			(!! ) =>{
+				eager!{
+					const N: [i32; 3] = [test_macro![1], test_macro![1], test_macro![1]];
+				}
+			};
+			( 1 ) =>{
+				[5][0] + 5
+			};
+		}
+	}
+	test_macro!(!!);
+	#[test]
+	fn test(){
+		assert_eq!([10, 10, 10], N);
+	}
+}
+mod bracket_test_nested_calls{
+	/*
+	Tests that a macro call can be nested, where the input to one macro is the expansion of another.
+	*/
+	macro_rules ! test_macro_1 {
+		(!!) =>{
+			eager!{
+				const N: [i32; 3] = test_macro_2![test_macro_3![test_macro_4![]]];
+			}
+		};
+	}
+	eager_macro_rules!{$eager_1
+		macro_rules! test_macro_2{
+			( $($all:tt)* ) =>{
+				[$($all)*, 2]
+			};
+		}
+		
+		macro_rules! test_macro_3{
+			( $($all:tt)* ) =>{
+				1, $($all)*
+			};
+		}
+	
+		macro_rules! test_macro_4{
+			( ) =>{
+				4
+			};
+		}
+	}
+	test_macro_1!(!!);
+	#[test]
+	fn test(){
+		assert_eq!([1, 4, 2], N);
+	}
+}
+mod bracket_test_non_call_block_ignored{
+	
+	eager_macro_rules!{$eager_1
+		macro_rules! test_macro{
+			() => {
+				eager!{
+					test_macro![1]
+					[4 + 4][0]
+				}
+			};
+			( 1 ) => {
+				4 +
+			};
+		}
+	}
+	#[test]
+	fn test(){
+		assert_eq!(12, test_macro!());
+	}
+}
+mod bracket_test_nested_eagers{
+	/*
+	Tests that using the eager! macro inside the body of another eager! call
+	does nothing.
+	*/
+	eager_macro_rules!{$eager_1
+		macro_rules! test_macro{
+			() => {
+				eager![
+					1
+					eager![
+						test_macro![1]
+					]
+				]
+			};
+			( 1 ) => {
+				+ 2
+			};
+		}
+	}
+	#[test]
+	fn test(){
+		assert_eq!(3, test_macro!());
+	}
+}
+mod bracket_test_recursive_eagers{
+	/*
+	Tests that if an expansion creates a new 'eager!' call, it is ignored.
+	*/
+	eager_macro_rules!{$eager_1
+		macro_rules! test_macro{
+			() => {
+				eager![
+					1 test_macro![1]
+				]
+			};
+			( 1 ) => {
+				eager![+ 2]
+			};
+		}
+	}
+	#[test]
+	fn test(){
+		assert_eq!(3, test_macro!());
+	}
+}
+mod test_attributes_are_not_calls{
+	/*
+	Tests that outer and inner attributes are decoded as '[]' blocks,
+	and that '#![...]' isn't mistaken for a macro call.
+	*/
+	eager_macro_rules!{$eager_1
+		macro_rules! test_macro{
+			() => { SomeStruct };
+			(derives) => { Debug, PartialEq };
+		}
+	}
+	eager!{
+		mod inner{
+			#![allow(dead_code)]
+			#[derive(test_macro![derives])]
+			pub struct test_macro![];
+		}
+	}
+	#[test]
+	fn test(){
+		assert_eq!(inner::SomeStruct, inner::SomeStruct);
+	}
+}
+mod test_logical_not_isnt_a_call{
+	/*
+	Tests that a '!' after a keyword or an operator is a logical not and not a macro call,
+	even if it is followed by a block.
+	*/
+	eager_macro_rules!{$eager_1
+		macro_rules! test_macro{
+			() => {true};
+			($e:expr) => {$e};
+		}
+	}
+	
+	fn return_not(a: bool) -> bool {
+		eager!{
+			return !(a && test_macro!())
+		}
+	}
+	
+	#[test]
+	fn test_if(){
+		assert_eq!(1, eager!{if !(false && test_macro!()) {1} else {2}});
+		assert_eq!(2, eager!{if !(true && test_macro!()) {1} else if !(test_macro!()) {3} else {2}});
+	}
+	#[test]
+	fn test_while(){
+		let mut i = 0;
+		eager!{
+			while !(i >= test_macro!(3)) {
+				i += 1;
+			}
+		}
+		assert_eq!(3, i);
+	}
+	#[test]
+	fn test_match(){
+		let x = eager!{
+			match !(test_macro!()) {
+				true => 1,
+				false => 2,
+			}
+		};
+		assert_eq!(2, x);
+	}
+	#[test]
+	fn test_return(){
+		assert!(return_not(false));
+		assert!(!return_not(true));
+	}
+	#[test]
+	fn test_operators(){
+		let a = true;
+		let b = false;
+		assert!(eager!{a == test_macro!() && !(b)});
+		assert!(eager!{b || !(test_macro!(b))});
+		assert!(eager!{!(b) & !(test_macro!(b)) | !![test_macro!(a)][0]});
+		let c = eager!{!(test_macro!(b))};
+		assert!(c);
+		let d = eager!{match a { true => !(b), false => b }};
+		assert!(d);
+	}
+}
+mod test_fuel{
+	/*
+	Tests that giving 'eager!' fuel doesn't change the result, as long as there is enough.
+	The smallest amounts given are exactly enough.
+	*/
+	eager_macro_rules!{$eager_1
+		macro_rules! test_macro{
+			() => {1};
+			($e:expr) => {$e + test_macro!()};
+		}
+	}
+	
+	#[test]
+	fn test(){
+		assert_eq!(3, eager!{@fuel[2 1] test_macro!() + test_macro!() + test_macro!()});
+		assert_eq!(3, eager!{@fuel[4 7] test_macro!(test_macro!(test_macro!()))});
+		assert_eq!(2, eager!{@fuel[2 7] test_macro!(test_macro![])});
+		assert_eq!(2, eager!{@fuel[0 2 7] test_macro!(test_macro![])});
+		assert_eq!(2, eager!{@fuel[2 5 6] test_macro!(test_macro![])});
+		assert_eq!(2, eager!{@fuel[2 0 0 0] test_macro!(test_macro![])});
+		assert_eq!(1, eager!{@fuel[1] 1});
+	}
+}
+mod test_trace{
+	/*
+	Tests that 'eager_trace!' records the level of each eager macro call and the final level.
+	*/
+	eager_macro_rules!{$eager_1
+		macro_rules! test_macro_1{
+			() => {2, 3};
+		}
+		macro_rules! test_macro_2{
+			($e1:expr, $e2:expr) => {$e1 + $e2};
+		}
+	}
+	
+	#[test]
+	fn test(){
+		let trace: &'static [&'static str] = eager_trace!{1 + test_macro_2!(test_macro_1!())};
+		assert_eq!(3, trace.len());
+		assert!(trace[0].contains("test_macro_1"));
+		assert!(trace[1].contains("test_macro_2"));
+		assert!(trace[1].contains("(2, 3)"));
+		assert!(!trace[2].contains("test_macro"));
+		
+		assert_eq!(1, eager_trace!{1 + 2}.len());
+		// How tokens are spaced when stringified depends on the compiler version
+		assert_eq!("[[][][1+2{3}][]]", eager_trace!{1 + 2 {3}}[0].replace(" ", ""));
+		assert_eq!(2, eager_trace!{@fuel[1 0 0] lazy!{test_macro_1!()} test_macro_1!()}.len());
+	}
+}
+mod test_depth{
+	/*
+	Tests that '@depth[n]' only expands the macro calls that are the result of
+	fewer than n expansions, leaving the rest to be expanded lazily.
+	*/
+	eager_macro_rules!{$eager_1
+		macro_rules! one{
+			() => {1};
+		}
+		macro_rules! call_is_call{
+			() => {is_call!(one!())};
+		}
+		macro_rules! call_call_is_call{
+			() => {call_is_call!()};
+		}
+		macro_rules! block_call_is_call{
+			() => {(call_is_call!())};
+		}
+	}
+	
+	#[test]
+	fn test(){
+		assert_eq!(0, eager!{call_is_call!()});
+		assert_eq!(1, eager!{@depth[1] call_is_call!()});
+		assert_eq!(0, eager!{@depth[1] is_call!(one!())});
+		assert_eq!(1, eager!{@depth[2] call_call_is_call!()});
+		assert_eq!(0, eager!{@depth[3] call_call_is_call!()});
+		assert_eq!(1, eager!{@depth[0] is_call!(one!())});
+		assert_eq!(1, eager!{@fuel[1 0 0] @depth[1] call_is_call!()});
+	}
+	#[test]
+	fn test_depth_is_per_expansion(){
+		assert_eq!(0, eager!{@depth[2] call_is_call!() + call_is_call!()});
+		assert_eq!(1, eager!{@depth[2] block_call_is_call!() + call_is_call!()});
+	}
+}
+mod test_only_and_except{
+	/*
+	Tests that '@only[...]' only expands the listed macros and that '@except[...]'
+	expands all but the listed macros, the rest being treated as if in a 'lazy!' block.
+	*/
+	eager_macro_rules!{$eager_1
+		macro_rules! one{
+			() => {1};
+		}
+	}
+	
+	#[test]
+	fn test(){
+		assert_eq!(0, eager!{is_call!(one!())});
+		assert_eq!(1, eager!{@only[is_call] is_call!(one!())});
+		assert_eq!(0, eager!{@only[is_call, one] is_call!(one!())});
+		assert_eq!(2, eager!{@only[one] one!() + lazy_is_call!(one!())});
+		assert_eq!(1, eager!{@except[one] is_call!(one!())});
+		assert_eq!(1, eager!{@except[is_call] is_call!(one!())});
+		assert_eq!(0, eager!{@except[is_call] one!() - is_call![one!()]});
+		assert_eq!(2, eager!{@fuel[1 0 0] @except[is_call] {one!() + is_call!{one!()}}});
+	}
+	macro_rules! plain{
+		($e:expr) => {$e};
+	}
+	
+	#[test]
+	fn test_non_eager_macros(){
+		assert_eq!("1", eager!{@only[one] format!("{}", lazy!{one!()})});
+		assert_eq!(1, eager!{@only[is_call] is_call!(lazy_is_call!(one!()))});
+		assert_eq!(0, eager!{@except[plain] is_call!(one!())});
+		assert_eq!(2, eager!{@except[plain] lazy!{plain!(one!())} + one!()});
+		assert_eq!(1, eager!{@except[::not_in_scope::one, plain] is_call!(one!())});
+		assert_eq!(2, eager!{@except[plain] reverse_tt!([one!() + one!()])});
+		assert_eq!([1, 2], eager!{@except[plain] deep_reverse_tt!([one!() + one!(), one!()])});
+	}
+}
+mod test_std_macros{
+	/*
+	Tests that standard library macros are left for the compiler to expand,
+	while their input is still checked, unless it must be kept as is.
+	*/
+	eager_macro_rules!{$eager_1
+		macro_rules! one{
+			() => {1};
+		}
+	}
+	eager!{
+		thread_local!{
+			static ONE: u32 = one!();
+		}
+	}
+	
+	#[test]
+	fn test(){
+		use std::fmt::Write;
+		
+		assert_eq!(vec![1, 1], eager!{vec![one!(), one!()]});
+		assert_eq!("1", eager!{format!("{}", one!())});
+		assert_eq!(stringify!(one!()), eager!{stringify!(one!())});
+		assert_eq!("a1", eager!{concat!("a", stringify!(1))});
+		assert_eq!(1, eager!{is_call!(vec![one!()])});
+		
+		let x = 1;
+		eager!{assert_eq!(x, one!())};
+		
+		let mut s = String::new();
+		eager!(write!(s, "{}", one!())).unwrap();
+		assert_eq!("1", s);
+		
+		#[allow(deprecated)]
+		let r: Result<u32, ()> = (|| Ok(eager!{try!(Ok(one!()))}))();
+		assert_eq!(Ok(1), r);
+		
+		ONE.with(|one| assert_eq!(1, *one));
+		eager!{
+			::std::thread_local!{
+				static TWO: u32 = one!() + one!();
+			}
+		}
+		TWO.with(|two| assert_eq!(2, *two));
+	}
+	#[test]
+	fn test_paths(){
+		let x = 1;
+		assert_eq!("1one", eager!{::std::format!("{}{}", one!(), ::std::stringify!(one))});
+		eager!{::std::debug_assert_eq!(x, ::std::convert::identity(one!()))};
+		assert_eq!(std::f64::consts::PI, eager!{std::f64::consts::PI});
+		assert_eq!(Vec::<u8>::new(), eager!{core::convert::identity(::std::vec::Vec::<u8>::new())});
+		assert_eq!("1one", eager!{std::format!("{}{}", one!(), std::stringify!(one))});
+		eager!{core::assert_eq!(x, one!())};
+		assert_eq!(vec![1, 1], eager!{std::vec![one!(), one!()]});
+	}
+}
+mod test_into{
+	/*
+	Tests that '@into[...]' gives the fully expanded result to the given macro
+	instead of outputting it.
+	*/
+	eager_macro_rules!{$eager_1
+		macro_rules! one{
+			() => {1};
+		}
+	}
+	macro_rules! count{
+		() => {0};
+		($first:tt $($rest:tt)*) => {1 + count!($($rest)*)};
+	}
+	
+	#[test]
+	fn test(){
+		assert_eq!(3, eager!{@into[count!] one!() one!() one!()});
+		assert_eq!(0, eager!{@into[count!]});
+		assert_eq!(2, eager!{@into[reverse_tt!] [one!() - 3]});
+		assert_eq!(1, eager!{@into[lazy_is_call!] lazy!{one!()}});
+		assert_eq!(2, eager!{@fuel[1 0 0] @depth[1] @into[count!] {one!()} one!()});
+		assert_eq!("1 + 1", eager!{@into[::std::stringify!] one!() + one!()});
+	}
+}
+mod test_hand_written_protocol{
+	/*
+	Tests that a macro can be eager!-enabled by hand using the stable subset of the protocol,
+	returning the state to the path given in it.
+	*/
+	macro_rules! two_and_three{
+		(@__dmutil_eager[@__dmutil_version $version:tt [$($return:tt)*] $($state:tt)*]) => {
+			$($return)*!{@from_macro[@__dmutil_version $version [$($return)*] $($state)*] 2, 3}
+		};
+		(
+			@__dmutil_eager_args[@__dmutil_version $version:tt [$($return:tt)*] $($state:tt)*]
+			$($input:tt)*
+		) => {
+			$($return)*!{@scan_args[@__dmutil_version $version [$($return)*] $($state)*] $($input)*}
+		};
+		() => {2, 3};
+	}
+	eager_macro_rules!{$
+		macro_rules! add{
+			($e1:expr, $e2:expr) => {$e1 + $e2};
+		}
+	}
+	
+	#[test]
+	fn test(){
+		assert_eq!(5, eager!{add!(two_and_three!())});
+		assert_eq!(5, eager!{add!{two_and_three!{}}});
+		assert_eq!(10, eager!{@fuel[1 0 0] add!(two_and_three!()) + add!(two_and_three!())});
+	}
+}
+mod test_simple_token_runs{
+	/*
+	Tests that tokens that aren't simple are found wherever they are among the next tokens,
+	even though simple tokens are moved to the prefix several at a time.
+	*/
+	eager_macro_rules!{$
+		macro_rules! one{
+			() => {1};
+		}
+	}
+	
+	#[test]
+	fn test(){
+		assert_eq!([1; 1], eager!{[one!()]});
+		assert_eq!([1; 2], eager!{[1, one!()]});
+		assert_eq!([1; 3], eager!{[1, 1, one!()]});
+		assert_eq!([1; 4], eager!{[1, 1, 1, one!()]});
+		assert_eq!([1; 5], eager!{[1, 1, 1, 1, one!()]});
+		assert_eq!([1; 6], eager!{[1, 1, 1, 1, 1, one!()]});
+		assert_eq!([1; 7], eager!{[1, 1, 1, 1, 1, 1, one!()]});
+		assert_eq!([1; 8], eager!{[1, 1, 1, 1, 1, 1, 1, one!()]});
+		assert_eq!([1; 9], eager!{[1, 1, 1, 1, 1, 1, 1, 1, one!()]});
+		assert_eq!([1; 10], eager!{[1, 1, 1, 1, 1, 1, 1, 1, 1, one!()]});
+	}
+	#[test]
+	fn test_paths(){
+		assert_eq!([1; 4], eager!{[1, 1, dmutil::reverse_tt!{[one!()]}, 1]});
+		assert_eq!([1; 5], eager!{[1, 1, 1, dmutil::reverse_tt!{[one!()]}, 1]});
+		assert_eq!([1; 6], eager!{[1, 1, 1, 1, dmutil::reverse_tt!{[one!()]}, 1]});
+		assert_eq!([1, 1, 1, 7], eager!{[1, 1, one!(), ::std::vec![7][0]]});
+		assert_eq!("11", eager!{format!("{}{}", one!(), ::std::stringify!(1))});
+		assert_eq!(3, eager!{1 + 1 + 1 + 1 - 1 - 1 - 1 + 1 + dmutil::lazy!{1}});
+	}
+	#[test]
+	fn test_not_and_keywords(){
+		let x = 2u8;
+		assert_eq!(255, eager!{x + x - x + !x});
+		assert_eq!(255, eager!{x + x + x + x - x - x - x + !x});
+		assert_eq!(1, eager!{if x > 1 && x < 3 && !(x > 2) {one!()} else {0}});
+		assert_eq!(1, eager!{1 + 1 + 1 + 1 - 1 - 1 - 1 - lazy!{lazy_is_call!(a!())} + one!()});
+	}
+	#[test]
+	fn test_depth(){
+		assert_eq!(
+			[1; 12],
+			eager!{@depth[1] [1, 1, 1, 1, {one!()}, 1, 1, 1, 1, 1, 1, one!()]}
+		);
+	}
+}
+mod test_items{
+	/*
+	Tests that items and statements are each decoded on their own, and that they
+	can still use each other.
+	*/
+	eager_macro_rules!{$
+		macro_rules! one{
+			() => {1};
+		}
+		macro_rules! plus_one{
+			() => {+ 1};
+		}
+	}
+	
+	eager!{
+		#[derive(Debug, PartialEq)]
+		pub struct Point{
+			x: u32,
+			y: u32,
+		}
+		impl Point{
+			fn new() -> Self {
+				Point{x: one!(), y: one!() + one!()}
+			}
+		}
+		const ONE: u32 = one!();
+		fn sum(p: Point) -> u32 { p.x + p.y + ONE }
+		fn two() -> u32 { one!() + one!() }
+		mod inner{
+			pub fn three() -> u32 { super::two() + one!() }
+		}
+		static THREE: u32 = one!() + one!() + one!();
+	}
+	
+	// Each item gets the whole fuel, so this needs 22 and not 37
+	eager!{@fuel[2 2]
+		fn four() -> u32 { one!() + one!() + two() }
+		fn five() -> u32 { one!() + four() }
+	}
+	
+	#[test]
+	fn test(){
+		assert_eq!(Point{x: 1, y: 2}, Point::new());
+		assert_eq!(4, sum(Point::new()));
+		assert_eq!(3, inner::three());
+		assert_eq!(3, THREE);
+		assert_eq!(5, five());
+	}
+	#[test]
+	fn test_statements(){
+		let mut x = 0;
+		eager!{
+			let y = one!();
+			x += y;
+			if x > 0 { x += one!(); }
+			let z = [one!(); 2];
+			x += z.len();
+		}
+		assert_eq!(4, x);
+	}
+	#[test]
+	fn test_blocks_continuing_statements(){
+		eager!{
+			let x = if one!() > 0 { one!() } else { 0 };
+			let y = { one!() } as u64;
+			let z = { one!() } plus_one!{};
+		}
+		assert_eq!(1, x);
+		assert_eq!(1u64, y);
+		assert_eq!(2, z);
+	}
+	#[test]
+	fn test_statements_with_value(){
+		// The statements are expanded in sibling invocations,
+		// which must still give the value of the last expression
+		let x = {
+			eager!{
+				let y = one!();
+				let z = y + one!();
+				z * if z > y { 3 } else { 0 }
+			}
+		};
+		assert_eq!(6, x);
+		let w: u32 = {eager!{let v = one!(); v as u32 + { one!() }}};
+		assert_eq!(2, w);
+	}
+}
@@ -68,7 +68,7 @@ mod test_eager_vs_non_eager_expansion_order{
 	}
 	
 	In this case eager! would not work because when it calls the macro (mac1), the pure
-	rule will match the initial '@eager', which is not intended.
+	rule will match the initial '@__dmutil_eager', which is not intended.
 	*/
 	
 	eager_macro_rules! {$eager_1
@@ -175,4 +175,273 @@ mod test_rustdoc{
 	fn test(){
 		assert_eq!(1, test_macro_1!());
 	}
-}
\ No newline at end of file
+}
+mod test_lazy_args{
+	/*
+	Tests that macros and rules with '#[eager(lazy_args)]' are given their input
+	before it is scanned, while their expansion is still scanned.
+	*/
+	eager_macro_rules!{ $eager_1
+		macro_rules! one{
+			() => {1};
+		}
+		#[eager(lazy_args)]
+		macro_rules! raw_is_call{
+			($name:ident ! $args:tt) => {1};
+			($($other:tt)*) => {0};
+		}
+		#[eager(lazy_args)]
+		macro_rules! call_plus_1{
+			($name:ident ! $args:tt) => {$name!$args + 1};
+		}
+		macro_rules! mixed{
+			#[eager(lazy_args)]
+			(raw $name:ident ! $args:tt) => {1};
+			(raw $($other:tt)*) => {0};
+			(scanned $name:ident ! $args:tt) => {1};
+			(scanned $($other:tt)*) => {0};
+		}
+		macro_rules! lazy_first{
+			() => {2};
+			#[eager(lazy_args)]
+			() => {1};
+		}
+	}
+	#[test]
+	fn test(){
+		assert_eq!(1, eager!{raw_is_call!(one!())});
+		assert_eq!(0, eager!{raw_is_call!(1)});
+		assert_eq!(2, eager!{call_plus_1!(one!())});
+		assert_eq!(1, eager!{mixed!(raw one!())});
+		assert_eq!(0, eager!{mixed!(scanned one!())});
+		assert_eq!(1, eager!{lazy_first!()});
+		assert_eq!(1, eager!{lazy_first!() * lazy_first![] * lazy_first!{}});
+		
+		// Without 'eager!' the macros work as usual
+		assert_eq!(1, raw_is_call!(one!()));
+		assert_eq!(1, mixed!(scanned one!()));
+	}
+}
+mod test_no_rescan{
+	/*
+	Tests that the expansions of macros and rules with '#[eager(no_rescan)]' are not
+	checked for macro calls by 'eager!'.
+	*/
+	eager_macro_rules!{ $eager_1
+		macro_rules! one{
+			() => {1};
+		}
+		#[eager(no_rescan)]
+		macro_rules! verbatim{
+			() => {is_call!(one!())};
+		}
+		macro_rules! mixed{
+			#[eager(no_rescan)]
+			(verbatim) => {is_call!(one!())};
+			(rescan) => {is_call!(one!())};
+		}
+		macro_rules! lazy_verbatim{
+			#[eager(lazy_args, no_rescan)]
+			($($input:tt)*) => {is_call!($($input)*)};
+		}
+	}
+	#[test]
+	fn test(){
+		assert_eq!(1, eager!{verbatim!()});
+		assert_eq!(3, eager!{2 * verbatim!() + 1});
+		assert_eq!(4, eager!{(verbatim!() + one!()) * 2});
+		assert_eq!(1, eager!{mixed!(verbatim)});
+		assert_eq!(0, eager!{mixed!(rescan)});
+		assert_eq!(1, eager!{lazy_verbatim!(one!())});
+	}
+}
+mod test_no_auxiliary_variable{
+	/*
+	Tests that the auxiliary variable can be left out, and that when it is given,
+	rules may use a macro variable with the same name.
+	*/
+	eager_macro_rules!{ $
+		macro_rules! one{
+			() => {1};
+		}
+	}
+	eager_macro_rules!{ $eager_1
+		macro_rules! add{
+			($eager_1:expr, $e2:expr) => {$eager_1 + $e2};
+		}
+		macro_rules! state{
+			($__dmutil_state:tt) => {$__dmutil_state};
+		}
+	}
+	#[test]
+	fn test(){
+		assert_eq!(1, one!());
+		assert_eq!(2, eager!{add!(one!(), one!())});
+		assert_eq!(3, add!(1, 2));
+		assert_eq!(1, eager!{state!(one!())});
+	}
+}
+mod test_rules_accepting_eager{
+	/*
+	Tests that rules may accept '@eager' as the first token, and that the
+	old '@eager' marker is still accepted by macros with no such rules.
+	*/
+	eager_macro_rules!{ $
+		macro_rules! marker{
+			(@eager $e:expr) => {$e + 1};
+			(@eager_xxx $e:expr) => {$e + 2};
+			($e:expr) => {$e};
+		}
+		macro_rules! one{
+			() => {1};
+		}
+	}
+	#[test]
+	fn test(){
+		assert_eq!(2, marker!(@eager 1));
+		assert_eq!(3, marker!(@eager_xxx 1));
+		assert_eq!(2, eager!{marker!(@eager one!())});
+		assert_eq!(3, eager!{marker!(@eager_xxx one!())});
+		assert_eq!(1, one!{@eager[[[][][][]]]});
+		assert_eq!(8, one!{@eager[[[] [] [+ 2 * 4] [- 1]]]});
+		assert_eq!([4, 1], one!{@eager[[[] [] [+ 3] [, 1]] [[] [] [] [] []]]});
+	}
+}
+mod test_body_delimiters{
+	/*
+	Tests that the rules of a declared macro can be in any type of block,
+	optionally followed by ';'.
+	*/
+	eager_macro_rules!{ $eager_1
+		macro_rules! brace{
+			() => {1};
+		}
+		macro_rules! paren(
+			() => {2};
+		);
+		macro_rules! bracket[
+			() => {3};
+		];
+		macro_rules! brace_semicolon{
+			() => {4};
+		};
+		macro_rules! paren_no_semicolon(
+			() => {5};
+		)
+	}
+	eager_macro_rules!{ $
+		macro_rules! paren_without_auxiliary(
+			() => {6};
+		);
+	}
+	#[test]
+	fn test(){
+		assert_eq!(1, brace!());
+		assert_eq!(2, paren!());
+		assert_eq!(3, bracket!());
+		assert_eq!(4, brace_semicolon!());
+		assert_eq!(5, paren_no_semicolon!());
+		assert_eq!(6, paren_without_auxiliary!());
+		assert_eq!(21, eager!{brace!() + paren!() + bracket!() + brace_semicolon!()
+			+ paren_no_semicolon!() + paren_without_auxiliary!()});
+	}
+}
+mod test_rule_attributes{
+	/*
+	Tests that rules can be given '#[cfg(...)]' and '#[cfg_attr(...)]'.
+	*/
+	eager_macro_rules!{ $
+		macro_rules! one{
+			() => {1};
+		}
+		macro_rules! configured{
+			#[cfg(all())]
+			(a) => {1};
+			#[cfg(any())]
+			(a) => {2};
+			#[cfg(any())]
+			#[eager(lazy_args)]
+			(b) => {3};
+			#[cfg(test)]
+			#[cfg_attr(test, eager(lazy_args))]
+			(c $name:ident ! $args:tt) => {4};
+			#[cfg(not(test))]
+			(c $($other:tt)*) => {5};
+			#[cfg_attr(any(), eager(lazy_args))]
+			(d $name:ident ! $args:tt) => {6};
+			#[cfg_attr(all(), cfg(any()))]
+			(e) => {7};
+			#[cfg_attr(all(), cfg(all()), eager(lazy_args),)]
+			(f $name:ident ! $args:tt) => {8};
+			($($other:tt)*) => {0};
+		}
+	}
+	#[test]
+	fn test(){
+		assert_eq!(1, configured!(a));
+		assert_eq!(0, configured!(b));
+		assert_eq!(4, configured!(c one!()));
+		assert_eq!(1, eager!{configured!(a)});
+		assert_eq!(0, eager!{configured!(b)});
+		assert_eq!(4, eager!{configured!(c one!())});
+		assert_eq!(0, eager!{configured!(c 1)});
+		assert_eq!(2, eager!{configured!(a) + one!()});
+		assert_eq!(6, configured!(d one!()));
+		assert_eq!(0, eager!{configured!(d one!())});
+		assert_eq!(0, configured!(e));
+		assert_eq!(8, eager!{configured!(f one!())});
+	}
+}
+mod test_only{
+	/*
+	Tests that macros and rules with '#[eager(only)]' are only declared for use inside 'eager!'.
+	*/
+	eager_macro_rules!{ $
+		#[eager(only)]
+		macro_rules! add_1{
+			($e:expr) => {$e + 1};
+		}
+		#[eager(only, no_rescan)]
+		macro_rules! verbatim{
+			() => {eager!{add_1!(1)}};
+		}
+		macro_rules! mixed{
+			#[eager(only)]
+			(eager) => {1};
+			($($other:tt)*) => {0};
+		}
+	}
+	#[test]
+	fn test(){
+		assert_eq!(2, eager!{add_1!(1)});
+		assert_eq!(3, eager!{add_1!(add_1!(1))});
+		assert_eq!(2, eager!{verbatim!()});
+		assert_eq!(1, eager!{mixed!(eager)});
+		assert_eq!(0, mixed!(eager));
+		// Earlier versions of 'eager!' call it with the old marker
+		assert_eq!(2, add_1!{@eager[[[][][][]]] 1});
+	}
+}
+mod test_assert_eager_enabled{
+	/*
+	Tests that 'assert_eager_enabled!' accepts 'eager!'-enabled macros.
+	That it rejects other macros is tested in its documentation.
+	*/
+	eager_macro_rules!{ $
+		macro_rules! one{
+			() => {1};
+		}
+		#[eager(only)]
+		macro_rules! two{
+			() => {2};
+		}
+	}
+	assert_eager_enabled!(one);
+	assert_eager_enabled!(one, two, reverse_tt,);
+	
+	#[test]
+	fn test(){
+		assert_eager_enabled!(one);
+		assert_eq!(3, eager!{one!() + two!()});
+	}
+}
@@ -0,0 +1,16 @@
+
+/*
+Macros used by the tests of several modules.
+*/
+eager_macro_rules!{$eager_1
+	/// Whether the input is a macro call, i.e. whether it was left unexpanded
+	macro_rules! is_call{
+		($name:ident ! $args:tt) => {1};
+		($($other:tt)*) => {0};
+	}
+}
+/// Like `is_call!`, but not `eager!`-enabled
+macro_rules! lazy_is_call{
+	($name:ident ! $args:tt) => {1};
+	($($other:tt)*) => {0};
+}
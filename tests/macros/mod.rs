@@ -1,4 +1,6 @@
 
+#[macro_use]
+mod helpers;
 mod eager;
 mod eager_macro_rules;
-mod lazy;
\ No newline at end of file
+mod lazy;
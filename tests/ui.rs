@@ -0,0 +1,113 @@
+/*
+Tests the errors the macros emit, by compiling each file in 'tests/ui' against this crate
+and checking that it fails with every message given by a '// error: ' line in the file.
+*/
+use std::{
+	env, fs,
+	path::{Path, PathBuf},
+	process::Command,
+	sync::Once,
+};
+
+fn rustc() -> Command {
+	Command::new(env::var_os("RUSTC").unwrap_or_else(|| "rustc".into()))
+}
+
+/// Compiles this crate's library once for all tests and returns its path. It is compiled here,
+/// since the ones cargo builds are only in 'deps', where other builds of it may be too
+fn dmutil_rlib() -> PathBuf {
+	static COMPILE: Once = Once::new();
+	let exe = env::current_exe().unwrap();
+	let out_dir = exe.parent().unwrap().parent().unwrap().join("ui");
+	let rlib = out_dir.join("libdmutil.rlib");
+	COMPILE.call_once(|| {
+		let output = rustc()
+			.arg("--crate-type=rlib")
+			.arg("--crate-name=dmutil")
+			.arg("--out-dir")
+			.arg(&out_dir)
+			.arg(Path::new(env!("CARGO_MANIFEST_DIR")).join("src/lib.rs"))
+			.output()
+			.unwrap();
+		assert!(
+			output.status.success(),
+			"the 'dmutil' library failed to compile:\n{}",
+			String::from_utf8_lossy(&output.stderr)
+		);
+	});
+	rlib
+}
+
+/// Compiles 'tests/ui/<name>.rs' and checks that it fails with the errors it lists
+#[allow(clippy::manual_strip)] // 'strip_prefix' is newer than the minimum Rust version
+fn check(name: &str) {
+	let file = Path::new(env!("CARGO_MANIFEST_DIR")).join("tests/ui").join(name).with_extension("rs");
+	let source = fs::read_to_string(&file).unwrap();
+	let out_dir = env::temp_dir().join(format!("dmutil_ui_{}_{}", std::process::id(), name));
+
+	let output = rustc()
+		.arg("--edition=2018")
+		.arg("--crate-type=lib")
+		.arg("--emit=metadata")
+		.arg("--crate-name")
+		.arg(format!("ui_{}", name))
+		.arg("--out-dir")
+		.arg(&out_dir)
+		.arg("--extern")
+		.arg(format!("dmutil={}", dmutil_rlib().display()))
+		.arg(&file)
+		.output()
+		.unwrap();
+	let _ = fs::remove_dir_all(&out_dir);
+	let stderr = String::from_utf8_lossy(&output.stderr);
+
+	assert!(!output.status.success(), "'{}' compiled, but should have failed", name);
+	for line in source.lines() {
+		let line = line.trim();
+		if line.starts_with("// error: ") {
+			let error = &line["// error: ".len()..];
+			assert!(
+				stderr.contains(error),
+				"'{}' did not fail with '{}', but with:\n{}",
+				name,
+				error,
+				stderr
+			);
+		}
+	}
+}
+
+#[test]
+fn fuel(){
+	check("fuel");
+}
+
+#[test]
+fn fuel_digits(){
+	check("fuel_digits");
+}
+
+#[test]
+fn except_non_eager(){
+	check("except_non_eager");
+}
+
+#[test]
+fn rule_attribute(){
+	check("rule_attribute");
+}
+
+#[test]
+fn eager_non_eager(){
+	check("eager_non_eager");
+}
+
+#[test]
+fn assert_non_eager(){
+	check("assert_non_eager");
+}
+
+#[test]
+fn assert_catch_all(){
+	check("assert_catch_all");
+}
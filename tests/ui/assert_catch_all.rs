@@ -0,0 +1,10 @@
+#[macro_use]
+extern crate dmutil;
+
+macro_rules! catch_all{
+	($($input:tt)*) => {1};
+}
+
+assert_eager_enabled!(catch_all);
+
+// error: __macro_is_not_eager_enabled_so_wrap_it_in_lazy
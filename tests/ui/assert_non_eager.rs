@@ -0,0 +1,13 @@
+#[macro_use]
+extern crate dmutil;
+
+macro_rules! plain{
+	() => {1};
+}
+
+assert_eager_enabled!(plain);
+
+// error: no rules expected
+// error: `"assert_eager_enabled!: this macro is not eager!-enabled, so wrap it in lazy! inside eager!"`
+// error: macro_rules! plain{
+// error: assert_eager_enabled!(plain);
@@ -0,0 +1,15 @@
+#[macro_use]
+extern crate dmutil;
+
+macro_rules! plain{
+	() => {1};
+}
+
+pub fn f() -> i32 {
+	eager!{plain!()}
+}
+
+// error: no rules expected
+// error: `@`
+// error: macro_rules! plain{
+// error: eager!{plain!()}
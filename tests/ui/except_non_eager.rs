@@ -0,0 +1,20 @@
+#[macro_use]
+extern crate dmutil;
+
+eager_macro_rules!{ $eager_1
+	macro_rules! one{
+		()=>{1};
+	}
+}
+
+macro_rules! plain{
+	($e:expr)=>{$e};
+}
+
+pub fn f() -> i32 {
+	eager!{@except[plain] plain!(one!()) + one!()}
+}
+
+// error: no rules expected
+// error: `@`
+// error: macro_rules! plain{
@@ -0,0 +1,14 @@
+#[macro_use]
+extern crate dmutil;
+
+eager_macro_rules!{ $eager_1
+	macro_rules! forever{
+		()=>{forever!()};
+	}
+}
+
+// With the default recursion limit
+eager!{@fuel[1 0 0] forever!()}
+
+// error: eager!: ran out of fuel before expanding 'forever !'
+// error: Remaining input: 'forever
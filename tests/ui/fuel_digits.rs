@@ -0,0 +1,6 @@
+#[macro_use]
+extern crate dmutil;
+
+eager!{@fuel[2000] 1}
+
+// error: eager!: the value of '@fuel' must be a number with its digits separated by spaces, e.g. '@fuel[1 2 0]', but was '2000'
@@ -0,0 +1,12 @@
+#[macro_use]
+extern crate dmutil;
+
+eager_macro_rules!{ $
+	macro_rules! documented{
+		/// Documentation of a rule
+		() => {1};
+	}
+}
+
+// error: eager_macro_rules!: a rule of 'documented' was given '#[doc
+// error: but rules can only be given '#[eager(...)]', '#[cfg(...)]' and '#[cfg_attr(...)]'